@@ -1,12 +1,10 @@
+use advent_of_code::template::param;
 use advent_of_code::vector::{linear_distance, Vector3d};
 
 advent_of_code::solution!(8);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    solve_part1(input, 1000)
-}
-
-fn solve_part1(input: &str, no_of_connections: usize) -> Option<u64> {
+    let no_of_connections = param("connections", 1000);
     let junction_boxes = parse_junction_boxes(input);
 
     let mut connections = get_all_distances(&junction_boxes);
@@ -149,15 +147,5 @@ struct Connection<'a> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = solve_part1(&advent_of_code::template::read_file("examples", DAY), 10);
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
-    }
+    advent_of_code::example_tests!(example);
 }
@@ -4,27 +4,23 @@ use z3::{Optimize, SatResult};
 
 advent_of_code::solution!(10);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let solution = input
+pub fn part_one(input: &str) -> Result<u64, String> {
+    input
         .lines()
         .map(|line| Machine::from(line))
         .map(|machine| get_no_of_presses_for_lights(&machine))
-        .sum();
-
-    Some(solution)
+        .sum()
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let solution = input
+pub fn part_two(input: &str) -> Result<u64, String> {
+    input
         .lines()
         .map(|line| Machine::from(line))
         .map(|machine| get_no_of_presses_for_joltage(&machine))
-        .sum();
-
-    Some(solution)
+        .sum()
 }
 
-fn get_no_of_presses_for_lights(machine: &Machine) -> u64 {
+fn get_no_of_presses_for_lights(machine: &Machine) -> Result<u64, String> {
     let initial_lights = vec![false; machine.target_lights.len()];
     let mut known_states = vec![initial_lights.clone()];
     let mut states_to_check = VecDeque::from([LightState {
@@ -43,7 +39,7 @@ fn get_no_of_presses_for_lights(machine: &Machine) -> u64 {
             }
 
             if new_state.lights == machine.target_lights {
-                return new_state.no_of_presses;
+                return Ok(new_state.no_of_presses);
             }
 
             known_states.push(new_state.lights.clone());
@@ -51,10 +47,10 @@ fn get_no_of_presses_for_lights(machine: &Machine) -> u64 {
         }
     }
 
-    panic!("No solution found");
+    Err("No solution found for lights".to_string())
 }
 
-fn get_no_of_presses_for_joltage(machine: &Machine) -> u64 {
+fn get_no_of_presses_for_joltage(machine: &Machine) -> Result<u64, String> {
     let mut button_consts: Vec<Int> = vec![];
     for i in 0..machine.buttons.len() {
         button_consts.push(Int::fresh_const(format!("Button{}", i).as_str()));
@@ -87,14 +83,14 @@ fn get_no_of_presses_for_joltage(machine: &Machine) -> u64 {
 
     let sat_result = optimizer.check(&[]);
     if sat_result != SatResult::Sat {
-        panic!("No solution found");
+        return Err("No solution found for joltage".to_string());
     }
 
     let model = optimizer.get_model().unwrap();
-    button_consts
+    Ok(button_consts
         .iter()
         .map(|btn_cont| model.eval(btn_cont, true).unwrap().as_u64().unwrap())
-        .sum()
+        .sum())
 }
 
 
@@ -175,13 +171,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(33));
     }
 }
@@ -293,3 +293,241 @@ pub mod vector {
             .sqrt()
     }
 }
+
+pub mod ocr {
+    /// Letters of the 4x6 font, with `#` for lit and `.` for dark pixels.
+    /// Letters are trimmed to their lit columns, which is how they are split in [`parse_grid`].
+    const FONT_6: [(char, &str); 18] = [
+        ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+        ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+        ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+        ('E', "####\n#...\n###.\n#...\n#...\n####"),
+        ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+        ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+        ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+        ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+        ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+        ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+        ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+        ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+        ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+        ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+        ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+        ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+    ];
+
+    /// Letters of the 6x10 font.
+    const FONT_10: [(char, &str); 15] = [
+        (
+            'A',
+            "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'B',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+        ),
+        (
+            'C',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+        ),
+        (
+            'E',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'F',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'G',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+        ),
+        (
+            'H',
+            "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'J',
+            "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+        ),
+        (
+            'K',
+            "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+        ),
+        (
+            'L',
+            "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'N',
+            "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+        ),
+        (
+            'P',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'R',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+        ),
+        (
+            'X',
+            "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+        ),
+        (
+            'Z',
+            "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+        ),
+    ];
+
+    /// Reads the letters of a letter-art answer, e.g. the `Display` output of a screen.
+    /// Any character other than `.` and whitespace counts as a lit pixel.
+    pub fn parse_str(s: &str) -> Option<String> {
+        let grid: Vec<Vec<bool>> = s
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c != '.' && !c.is_whitespace())
+                    .collect()
+            })
+            .collect();
+
+        parse_grid(&grid)
+    }
+
+    /// Reads the letters in a grid of lit pixels, indexed as `grid[y][x]`.
+    /// Returns `None` if the grid is not 6 or 10 pixels high or contains an unknown letter.
+    pub fn parse_grid(grid: &[Vec<bool>]) -> Option<String> {
+        let is_blank = |row: &Vec<bool>| !row.contains(&true);
+        let start = grid.iter().position(|row| !is_blank(row))?;
+        let end = grid.iter().rposition(|row| !is_blank(row))?;
+        let rows = &grid[start..=end];
+
+        let font: &[(char, &str)] = match rows.len() {
+            6 => &FONT_6,
+            10 => &FONT_10,
+            _ => return None,
+        };
+
+        let width = rows.iter().map(Vec::len).max()?;
+        let is_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+        let mut letters = String::new();
+        let mut x = 0;
+
+        while x < width {
+            if !is_lit(x) {
+                x += 1;
+                continue;
+            }
+
+            // letters are separated by at least one dark column.
+            let letter_start = x;
+            while x < width && is_lit(x) {
+                x += 1;
+            }
+
+            let glyph = rows
+                .iter()
+                .map(|row| {
+                    (letter_start..x)
+                        .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            let (letter, _) = font.iter().find(|(_, pattern)| *pattern == glyph)?;
+            letters.push(*letter);
+        }
+
+        Some(letters)
+    }
+
+    #[cfg(feature = "test_lib")]
+    mod tests {
+        use super::{parse_grid, parse_str, FONT_10, FONT_6};
+
+        const HELLO: &str = "\
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.";
+
+        const NXZ: &str = "\
+#....#..#....#..######
+##...#..#....#.......#
+##...#...#..#........#
+#.#..#...#..#.......#.
+#.#..#....##.......#..
+#..#.#....##......#...
+#..#.#...#..#....#....
+#...##...#..#...#.....
+#...##..#....#..#.....
+#....#..#....#..######";
+
+        #[test]
+        fn reads_every_glyph() {
+            for (letter, pattern) in FONT_6.iter().chain(FONT_10.iter()) {
+                assert_eq!(parse_str(pattern), Some(letter.to_string()));
+            }
+        }
+
+        #[test]
+        fn reads_small_letters() {
+            assert_eq!(parse_str(HELLO), Some("HELLO".into()));
+        }
+
+        #[test]
+        fn reads_large_letters() {
+            assert_eq!(parse_str(NXZ), Some("NXZ".into()));
+        }
+
+        #[test]
+        fn trims_blank_rows_and_columns() {
+            let padded: Vec<String> = HELLO
+                .lines()
+                .map(|line| format!("   ..{}  ..", line.replace('#', "█")))
+                .collect();
+            let padded = format!("\n......\n{}\n\n", padded.join("\n"));
+
+            assert_eq!(parse_str(&padded), Some("HELLO".into()));
+        }
+
+        #[test]
+        fn reads_grids() {
+            let grid: Vec<Vec<bool>> = NXZ
+                .lines()
+                .map(|line| line.chars().map(|c| c == '#').collect())
+                .collect();
+
+            assert_eq!(parse_grid(&grid), Some("NXZ".into()));
+        }
+
+        #[test]
+        fn rejects_unknown_glyphs() {
+            let unknown = "#.#\n.#.\n#.#\n.#.\n#.#\n.#.";
+            assert_eq!(parse_str(unknown), None);
+
+            let hello_unknown = HELLO
+                .lines()
+                .zip(unknown.lines())
+                .map(|(a, b)| format!("{a}.{b}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            assert_eq!(parse_str(&hello_unknown), None);
+        }
+
+        #[test]
+        fn rejects_grids_of_other_heights() {
+            let five_rows = HELLO.lines().take(5).collect::<Vec<_>>().join("\n");
+            assert_eq!(parse_str(&five_rows), None);
+            assert_eq!(parse_str(""), None);
+            assert_eq!(parse_str("...\n..."), None);
+        }
+    }
+}
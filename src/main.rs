@@ -1,81 +1,166 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, progress, read, scaffold, solve, time, verify,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        all_years,
+        commands::{
+            solve::SolveOptions,
+            time::{ExportFormat, TimeOptions},
+        },
+        current_year, Day, Event, Puzzle,
+    };
+    use std::{path::PathBuf, process, time::Duration};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
             overwrite: bool,
         },
-        Solve {
-            day: Day,
+        Solve(SolveOptions),
+        Examples {
+            puzzle: Puzzle,
             release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            part: Option<u8>,
         },
         All {
+            years: Vec<u16>,
+            release: bool,
+            verify: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+        },
+        Verify {
+            years: Vec<u16>,
             release: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
-        Time {
-            all: bool,
-            day: Option<Day>,
-            store: bool,
+        Time(TimeOptions),
+        Progress {
+            year: u16,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: u16,
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // every command runs for the year passed with `--year`, or `AOC_YEAR` otherwise.
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => current_year().ok_or(
+                "no year set. Pass `--year <year>` or set `AOC_YEAR` in `.cargo/config.toml`.",
+            )?,
+        };
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                years: parse_years(&mut args, year),
                 release: args.contains("--release"),
+                verify: args.contains("--verify"),
+                jobs: parse_jobs(&mut args)?,
+                timeout: parse_timeout(&mut args)?,
+            },
+            Some("verify") => AppArguments::Verify {
+                years: parse_years(&mut args, year),
+                release: args.contains("--release"),
+                jobs: parse_jobs(&mut args)?,
+                timeout: parse_timeout(&mut args)?,
             },
             Some("time") => {
-                let all = args.contains("--all");
+                let years = parse_years(&mut args, year);
+                let run_all = args.contains("--all");
                 let store = args.contains("--store");
+                let timeout = parse_timeout(&mut args)?;
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let force = args.contains("--force");
+                let history = args.opt_value_from_str("--history")?;
+                let check = args.contains("--check");
+                let memory = args.contains("--memory");
+                let export_format: Option<ExportFormat> = args.opt_value_from_str("--export")?;
+
+                // the export path is the last free argument, optionally preceded by a day.
+                let (day, export) = match export_format {
+                    Some(format) => {
+                        let first: String = args.free_from_str()?;
+                        match args.opt_free_from_str::<PathBuf>()? {
+                            Some(path) => (Some(first.parse()?), Some((format, path))),
+                            None => (None, Some((format, PathBuf::from(first)))),
+                        }
+                    }
+                    None => (args.opt_free_from_str()?, None),
+                };
 
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
+                AppArguments::Time(TimeOptions {
+                    years,
+                    day,
+                    run_all,
                     store,
-                }
+                    timeout,
+                    compare,
+                    threshold,
+                    force,
+                    history,
+                    memory,
+                    check,
+                    export,
+                })
             }
+            Some("progress") => AppArguments::Progress { year },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args, year)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args, year)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args, year)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("solve") => AppArguments::Solve(SolveOptions {
+                puzzle: parse_puzzle(&mut args, year)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                profile: args.contains("--profile"),
+                record: args.contains("--record"),
+                part: args.opt_value_from_str("--part")?,
+                input: args.opt_value_from_str("--input")?,
+                example: args.contains("--example"),
+                example_part: args.opt_value_from_str("--example-part")?,
+                params: args.values_from_str("--param")?,
+            }),
+            Some("examples") => AppArguments::Examples {
+                puzzle: parse_puzzle(&mut args, year)?,
+                release: args.contains("--release"),
+                part: args.opt_value_from_str("--part")?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                year,
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,6 +178,57 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Returns every year with solutions if `--all-years` is passed, the selected year otherwise.
+    fn parse_years(args: &mut pico_args::Arguments, year: u16) -> Vec<u16> {
+        if args.contains("--all-years") {
+            all_years()
+        } else {
+            vec![year]
+        }
+    }
+
+    /// Parses the day of a puzzle and checks that it is part of the event of the year.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+        year: u16,
+    ) -> Result<Puzzle, Box<dyn std::error::Error>> {
+        let day: Day = args.free_from_str()?;
+        let event = Event::read(year);
+
+        if !event.contains(day) {
+            return Err(format!(
+                "day {day} is not part of the {year} event, which has {} days.",
+                event.days
+            )
+            .into());
+        }
+
+        Ok(Puzzle::new(year, day))
+    }
+
+    /// Parses the number of days that run concurrently with `--jobs`, which must be at least 1.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, pico_args::Error> {
+        let jobs = args.opt_value_from_fn(["-j", "--jobs"], |s| match s.parse::<usize>() {
+            Ok(0) => Err("the number of jobs must be at least 1".to_string()),
+            Ok(jobs) => Ok(jobs),
+            Err(e) => Err(format!("{e}")),
+        })?;
+        Ok(jobs.unwrap_or(1))
+    }
+
+    /// Parses the per-day `--timeout` in seconds, which must be a finite number above 0.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, pico_args::Error> {
+        args.opt_value_from_fn("--timeout", |s| {
+            let secs: f64 = s.parse().map_err(|e| format!("{e}"))?;
+            if secs <= 0_f64 {
+                return Err("the timeout must be above 0 seconds".to_string());
+            }
+            Duration::try_from_secs_f64(secs).map_err(|e| format!("{e}"))
+        })
+    }
 }
 
 fn main() {
@@ -102,43 +238,41 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                years,
+                release,
+                verify,
+                jobs,
+                timeout,
+            } => all::handle(&years, release, verify, jobs, timeout),
+            AppArguments::Verify {
+                years,
+                release,
+                jobs,
+                timeout,
+            } => verify::handle(&years, release, jobs, timeout),
+            AppArguments::Time(options) => time::handle(options),
+            AppArguments::Progress { year } => progress::handle(year),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
             AppArguments::Scaffold {
-                day,
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
             }
-            AppArguments::Solve {
-                day,
+            AppArguments::Solve(options) => solve::handle(options),
+            AppArguments::Examples {
+                puzzle,
                 release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+                part,
+            } => examples::handle(puzzle, release, part),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { year, wait } => today::handle(year, wait),
         },
     };
 }
@@ -0,0 +1,125 @@
+/// A global allocator that counts heap allocations, installed by `solution!` when the `count-allocations` feature is active.
+/// Unlike `dhat`, it only keeps a few atomic counters and works in regular release builds.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use crate::template::timings::MemoryUsage;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static TOTAL_BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Bytes that were allocated when counting was last reset.
+static BASELINE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    TOTAL_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    CURRENT_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Start counting from zero. Memory that is still allocated does not count towards the peak.
+pub fn reset() {
+    let current = CURRENT_BYTES.load(Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    TOTAL_BYTES.store(0, Ordering::Relaxed);
+    PEAK_BYTES.store(current, Ordering::Relaxed);
+    BASELINE_BYTES.store(current, Ordering::Relaxed);
+}
+
+/// Heap usage since the last [`reset`].
+pub fn usage() -> MemoryUsage {
+    let peak = PEAK_BYTES.load(Ordering::Relaxed);
+    let baseline = BASELINE_BYTES.load(Ordering::Relaxed);
+
+    MemoryUsage {
+        total_bytes: TOTAL_BYTES.load(Ordering::Relaxed),
+        peak_bytes: peak.saturating_sub(baseline) as u64,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use super::{reset, usage, CountingAllocator};
+
+    #[test]
+    fn counts_allocations() {
+        // NOTE: the allocator is called directly, so allocations of other tests are not counted.
+        let allocator = CountingAllocator;
+        let large = Layout::from_size_align(1024, 8).unwrap();
+        let small = Layout::from_size_align(256, 8).unwrap();
+
+        reset();
+
+        unsafe {
+            let a = allocator.alloc(large);
+            let b = allocator.alloc_zeroed(small);
+
+            let counted = usage();
+            assert_eq!(counted.allocations, 2);
+            assert_eq!(counted.total_bytes, 1280);
+            assert_eq!(counted.peak_bytes, 1280);
+
+            allocator.dealloc(a, large);
+            allocator.dealloc(b, small);
+        }
+
+        // the peak of a part only counts memory that was allocated after its freed predecessors.
+        reset();
+
+        unsafe {
+            let c = allocator.alloc(small);
+
+            let counted = usage();
+            assert_eq!(counted.allocations, 1);
+            assert_eq!(counted.total_bytes, 256);
+            assert_eq!(counted.peak_bytes, 256);
+
+            allocator.dealloc(c, small);
+        }
+    }
+}
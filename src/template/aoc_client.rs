@@ -0,0 +1,508 @@
+/// Module that talks to the Advent of Code website over HTTP, without requiring `aoc-cli`.
+/// If no session cookie is configured, requests fall back to the `aoc-cli` wrapper in [`aoc_cli`].
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::{aoc_cli, Puzzle};
+
+pub static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    /// The server responded with an error status, e.g. `404` for a day that is not unlocked yet.
+    BadStatus(u16),
+    Http(String),
+    IO(io::Error),
+    Cli(aoc_cli::AocCommandError),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `ADVENT_OF_CODE_SESSION` or create `~/.adventofcode.session`."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Http(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+            AocClientError::Cli(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<aoc_cli::AocCommandError> for AocClientError {
+    fn from(e: aoc_cli::AocCommandError) -> Self {
+        AocClientError::Cli(e)
+    }
+}
+
+/// An HTTP client for the Advent of Code website.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the base URL is read from `AOC_BASE_URL`, defaulting to the Advent of Code website.
+    ///  2. the session cookie is read with [`find_session`].
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = find_session().ok_or(AocClientError::SessionNotFound)?;
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Downloads the input of a puzzle.
+    pub fn input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Downloads the description of a puzzle as markdown.
+    /// Once part one is solved, the description contains both parts.
+    pub fn puzzle(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(puzzle))?;
+
+        Ok(articles(&html)
+            .iter()
+            .map(|article| html_to_markdown(article))
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Submits an answer and returns the response message of the server.
+    pub fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let html = read_response(response)?;
+
+        Ok(articles(&html)
+            .first()
+            .map_or_else(String::new, |article| html_to_text(article)))
+    }
+
+    fn day_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, _)) => Err(AocClientError::BadStatus(status)),
+        Err(e) => Err(AocClientError::Http(e.to_string())),
+    }
+}
+
+/// Reads the session cookie from, in order:
+///  1. the `ADVENT_OF_CODE_SESSION` environment variable.
+///  2. the file at `AOC_SESSION_FILE`.
+///  3. `~/.adventofcode.session` or `~/.config/adventofcode.session`, like `aoc-cli`.
+pub fn find_session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(session.trim().to_string());
+    }
+
+    let mut paths: Vec<PathBuf> = vec![];
+
+    if let Ok(path) = env::var("AOC_SESSION_FILE") {
+        paths.push(path.into());
+    }
+
+    if let Some(home) = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+        let home = PathBuf::from(home);
+        paths.push(home.join(".adventofcode.session"));
+        paths.push(home.join(".config").join("adventofcode.session"));
+    }
+
+    paths
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Where requests are sent to.
+enum Backend {
+    Http(AocClient),
+    Cli,
+}
+
+/// Uses the HTTP client if a session cookie is found, `aoc-cli` otherwise.
+/// Set `AOC_BACKEND=aoc-cli` to always use `aoc-cli`.
+fn backend() -> Result<Backend, AocClientError> {
+    let prefer_cli = env::var("AOC_BACKEND").is_ok_and(|x| x == "aoc-cli");
+
+    if !prefer_cli {
+        match AocClient::from_env() {
+            Ok(client) => return Ok(Backend::Http(client)),
+            Err(AocClientError::SessionNotFound) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    if aoc_cli::check().is_err() {
+        return Err(if prefer_cli {
+            aoc_cli::AocCommandError::CommandNotFound.into()
+        } else {
+            AocClientError::SessionNotFound
+        });
+    }
+
+    Ok(Backend::Cli)
+}
+
+/// Downloads the input and description of a puzzle to `data/YYYY/inputs` and `data/YYYY/puzzles`.
+pub fn download(puzzle: Puzzle) -> Result<(), AocClientError> {
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+    create_parent_dir(&input_path)?;
+    create_parent_dir(&puzzle_path)?;
+
+    match backend()? {
+        Backend::Cli => {
+            aoc_cli::download(puzzle)?;
+        }
+        Backend::Http(client) => {
+            fs::write(&input_path, client.input(puzzle)?)?;
+            fs::write(&puzzle_path, client.puzzle(puzzle)?)?;
+
+            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Downloads the description of a puzzle to `data/YYYY/puzzles` and prints it.
+pub fn read(puzzle: Puzzle) -> Result<(), AocClientError> {
+    let puzzle_path = get_puzzle_path(puzzle);
+    create_parent_dir(&puzzle_path)?;
+
+    match backend()? {
+        Backend::Cli => {
+            aoc_cli::read(puzzle)?;
+        }
+        Backend::Http(client) => {
+            let description = client.puzzle(puzzle)?;
+            fs::write(&puzzle_path, &description)?;
+            println!("{description}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits an answer and returns the response message.
+pub fn submit(puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocClientError> {
+    match backend()? {
+        Backend::Cli => {
+            let output = aoc_cli::submit(puzzle, part, answer)?;
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        Backend::Http(client) => client.submit(puzzle, part, answer),
+    }
+}
+
+fn get_input_path(puzzle: Puzzle) -> String {
+    puzzle.data_path("inputs", "txt")
+}
+
+fn get_puzzle_path(puzzle: Puzzle) -> String {
+    puzzle.data_path("puzzles", "md")
+}
+
+/// Creates the folder of a file, e.g. `data/2024/inputs` for a year that has no data yet.
+fn create_parent_dir(path: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = PathBuf::from(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the contents of all `<article>` elements, which hold puzzle descriptions and response messages.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(close) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + close]);
+        rest = &rest[content_start + close..];
+    }
+
+    articles
+}
+
+fn html_to_markdown(html: &str) -> String {
+    convert_html(html, true)
+}
+
+fn html_to_text(html: &str) -> String {
+    convert_html(html, false)
+}
+
+/// Converts the small subset of HTML used in puzzle descriptions.
+/// Without `markdown`, only the text and paragraph breaks are kept.
+fn convert_html(html: &str, markdown: bool) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut is_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        let markup = match (name, is_closing) {
+            ("p" | "h2" | "ul", true) => "\n\n".to_string(),
+            ("br", _) | ("li", true) => "\n".to_string(),
+            ("h2", false) if markdown => "## ".to_string(),
+            ("li", false) if markdown => "- ".to_string(),
+            ("pre", false) => {
+                is_pre = true;
+                if markdown { "```\n" } else { "" }.to_string()
+            }
+            ("pre", true) => {
+                is_pre = false;
+                if markdown { "```\n\n" } else { "\n" }.to_string()
+            }
+            ("code", _) if markdown && !is_pre => "`".to_string(),
+            ("em", _) if markdown => "*".to_string(),
+            ("a", false) if markdown => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                "[".to_string()
+            }
+            ("a", true) if markdown => format!("]({})", links.pop().unwrap_or_default()),
+            _ => String::new(),
+        };
+
+        out.push_str(&markup);
+    }
+
+    out.push_str(&decode_entities(rest));
+
+    // collapse the blank lines left behind by nested block elements.
+    let mut text = out.trim().to_string();
+    while text.contains("\n\n\n") {
+        text = text.replace("\n\n\n", "\n\n");
+    }
+    text
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use crate::{day, template::Puzzle};
+
+    use super::{convert_html, AocClient, AocClientError};
+
+    /// Starts a server that answers `responses.len()` requests in order and sends each raw request back over the channel.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8(request_body).unwrap());
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+
+                tx.send(request).unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, requests) = mock_server(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&base_url, "abc\n");
+
+        assert_eq!(
+            client.input(Puzzle::new(2025, day!(1))).unwrap(),
+            "1\n2\n3\n"
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn downloads_puzzle_as_markdown() {
+        let html = r#"<html><main><article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Count the <em>stars</em> in <code>a &lt; b</code>, see <a href="/2025/about">here</a>.</p><pre><code>1 2
+3 4
+</code></pre><ul><li>one</li><li>two</li></ul></article><p>Not part of the puzzle.</p></main></html>"#;
+        let (base_url, requests) = mock_server(vec![(200, html)]);
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.puzzle(Puzzle::new(2025, day!(1))).unwrap(),
+            "## --- Day 1: Test ---\n\nCount the *stars* in `a < b`, see [here](/2025/about).\n\n```\n1 2\n3 4\n```\n\n- one\n- two"
+        );
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /2025/day/1 HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let html = r#"<main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer. <a href="/2025/day/1#part2">[Continue to Part Two]</a></p></article></main>"#;
+        let (base_url, requests) = mock_server(vec![(200, html)]);
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.submit(Puzzle::new(2025, day!(1)), 2, "42").unwrap(),
+            "That's the right answer!  You are one gold star closer. [Continue to Part Two]"
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn errors_for_bad_status() {
+        let (base_url, _requests) = mock_server(vec![(404, "Not Found")]);
+        let client = AocClient::new(&base_url, "abc");
+
+        assert!(matches!(
+            client.input(Puzzle::new(2025, day!(25))),
+            Err(AocClientError::BadStatus(404))
+        ));
+    }
+
+    #[test]
+    fn converts_html_to_text() {
+        assert_eq!(
+            convert_html(
+                "<p>You gave an answer <em>too recently</em>.</p><p>Wait.</p>",
+                false
+            ),
+            "You gave an answer too recently.\n\nWait."
+        );
+    }
+}
@@ -1,5 +1,50 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    run_multi::{run_multi, RunOptions},
+    ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle(
+    years: &[u16],
+    is_release: bool,
+    is_verify: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+) {
+    let mut has_errors = false;
+
+    for (i, year) in years.iter().enumerate() {
+        if years.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            print_year_header(*year);
+        }
+
+        let run = run_multi(
+            *year,
+            &all_days(*year).collect(),
+            RunOptions {
+                is_release,
+                is_verify,
+                jobs,
+                timeout,
+                ..RunOptions::default()
+            },
+        );
+
+        has_errors |= run.has_errors();
+    }
+
+    if has_errors {
+        process::exit(1);
+    }
+}
+
+/// Separates the output of years when running several of them.
+pub fn print_year_header(year: u16) {
+    println!("{ANSI_BOLD}🎄 Advent of Code {year}{ANSI_RESET}");
+    println!("=======================");
 }
@@ -0,0 +1,31 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::Puzzle;
+
+/// Run all cases of the example manifest of a puzzle and exit with an error code if any of them fails.
+pub fn handle(puzzle: Puzzle, release: bool, part: Option<u8>) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    cmd_args.push("--".to_string());
+    cmd_args.push("--examples".to_string());
+
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    let mut cmd = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    if !cmd.wait().unwrap().success() {
+        process::exit(1);
+    }
+}
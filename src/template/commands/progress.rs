@@ -0,0 +1,13 @@
+use std::process;
+
+use crate::template::readme_progress;
+
+pub fn handle(year: u16) {
+    match readme_progress::update(year) {
+        Ok(()) => println!("Updated progress in the readme."),
+        Err(e) => {
+            eprintln!("Failed to update progress: {e}");
+            process::exit(1);
+        }
+    }
+}
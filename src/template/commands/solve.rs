@@ -1,28 +1,130 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{params, Puzzle};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Flags passed to the `solve` command.
+pub struct SolveOptions {
+    pub puzzle: Puzzle,
+    pub release: bool,
+    pub dhat: bool,
+    pub profile: bool,
+    pub record: bool,
+    pub submit: Option<u8>,
+    /// Only run this part.
+    pub part: Option<u8>,
+    /// Read the input from this file instead of `data/YYYY/inputs`. `-` reads from stdin.
+    pub input: Option<String>,
+    /// Read the input from `data/YYYY/examples`.
+    pub example: bool,
+    /// Read the input from the example file of a part, e.g. `data/2025/examples/01-2.txt`.
+    pub example_part: Option<u8>,
+    /// Parameters passed to the parts as `name=value`.
+    pub params: Vec<String>,
+}
+
+pub fn handle(options: SolveOptions) {
+    let custom_inputs = [
+        options.input.is_some(),
+        options.example,
+        options.example_part.is_some(),
+    ];
+
+    if let Some(e) = options.params.iter().find_map(|p| params::parse(p).err()) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    if custom_inputs.iter().filter(|x| **x).count() > 1 {
+        eprintln!("Only one of `--input`, `--example` and `--example-part` can be passed.");
+        process::exit(1);
+    }
+
+    // answers for other inputs or parameters must not end up in the answer store or on the website.
+    let is_custom = custom_inputs.contains(&true) || !options.params.is_empty();
+    if is_custom && (options.record || options.submit.is_some()) {
+        eprintln!(
+            "`--record` and `--submit` can only be used with the puzzle input and default params."
+        );
+        process::exit(1);
+    }
 
-    if dhat {
+    if [options.part, options.example_part, options.submit]
+        .iter()
+        .flatten()
+        .any(|part| !(1..=2).contains(part))
+    {
+        eprintln!("Parts must be either 1 or 2.");
+        process::exit(1);
+    }
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        options.puzzle.to_string(),
+    ];
+
+    if options.profile {
+        cmd_args.extend([
+            "--profile".to_string(),
+            "profiling".to_string(),
+            "--features".to_string(),
+            "profile".to_string(),
+        ]);
+    } else if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
+    // solutions count their allocations if this binary was built to do so.
+    if cfg!(feature = "count-allocations") {
+        cmd_args.extend(["--features".to_string(), "count-allocations".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if options.profile {
+        cmd_args.push("--profile".to_string());
+    }
+
+    if options.record {
+        cmd_args.push("--record".to_string());
+    }
+
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(part) = options.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(input) = options.input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
+    if options.example {
+        cmd_args.push("--example".to_string());
+    }
+
+    if let Some(example_part) = options.example_part {
+        cmd_args.push("--example-part".to_string());
+        cmd_args.push(example_part.to_string());
+    }
+
+    for param in options.params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(param);
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
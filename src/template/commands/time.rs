@@ -1,40 +1,323 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, path::PathBuf, process, time::Duration};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::commands::all::print_year_header;
+use crate::template::run_multi::{run_multi, RunOptions};
+use crate::template::timing_history::{format_timestamp, TimingHistory};
+use crate::template::timings::{format_bytes, format_nanos, PartTiming, TimingChange, Timings};
+use crate::template::timings_export::export;
+use crate::template::{
+    readme_benchmarks, year_path, Day, Event, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED,
+    ANSI_RESET,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub use crate::template::timings_export::ExportFormat;
 
-    let days_to_run = day.map_or_else(
+/// Flags passed to the `time` command.
+pub struct TimeOptions {
+    /// Years to benchmark, one after another.
+    pub years: Vec<u16>,
+    pub day: Option<Day>,
+    pub run_all: bool,
+    pub store: bool,
+    pub timeout: Option<Duration>,
+    /// Print the relative change of every part against the stored timings.
+    pub compare: bool,
+    /// Relative slowdown in percent above which a part counts as regressed.
+    pub threshold: f64,
+    /// Store timings even if parts regressed.
+    pub force: bool,
+    /// Print the stored timing history of a day instead of running benchmarks.
+    pub history: Option<Day>,
+    /// Measure heap usage of every part in a second run under `dhat`.
+    pub memory: bool,
+    /// Fail if a rendered benchmark table is out of date instead of running benchmarks.
+    pub check: bool,
+    /// Write the merged timings to a file.
+    pub export: Option<(ExportFormat, PathBuf)>,
+}
+
+pub fn handle(options: TimeOptions) {
+    let mut failed = false;
+
+    for (i, year) in options.years.iter().enumerate() {
+        if options.years.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            print_year_header(*year);
+        }
+
+        failed |= !handle_year(*year, &options);
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Runs the `time` command for a single year. Returns `false` if anything failed.
+fn handle_year(year: u16, options: &TimeOptions) -> bool {
+    if let Some(day) = options.history {
+        print_history(year, day);
+        return true;
+    }
+
+    if options.check {
+        return check_readme(year);
+    }
+
+    let stored_timings = Timings::read_from_file(year);
+    let event = Event::read(year);
+
+    let days_to_run = options.day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            if options.run_all {
+                event.days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                event
+                    .days()
+                    .filter(|day| !stored_timings.is_day_complete(*day, event.parts(*day)))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // NOTE: benchmarks always run sequentially to keep timings clean.
+    let run_options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        timeout: options.timeout,
+        // failures of the memory pass are reported along with the ones of this run.
+        is_quiet: options.memory,
+        ..RunOptions::default()
+    };
+    let mut run = run_multi(year, &days_to_run, run_options);
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+    let mut timings = run.timings.take().unwrap();
+
+    if options.memory {
+        println!("\n{ANSI_BOLD}Measuring heap usage{ANSI_RESET}");
+
+        let memory_run = run_multi(
+            year,
+            &days_to_run,
+            RunOptions {
+                is_memory: true,
+                timeout: options.timeout,
+                is_quiet: true,
+                ..RunOptions::default()
+            },
+        );
 
+        for (day, part, memory) in &memory_run.memory {
+            if let Some(timing) = timings
+                .data
+                .iter_mut()
+                .find(|t| t.day == *day)
+                .and_then(|t| t.part_mut(*part))
+            {
+                timing.memory = Some(*memory);
+            }
+        }
+
+        run.merge(memory_run);
+        run.print_report();
+    }
+
+    let mut failed = !run.failures.is_empty();
+
+    // heap usage is also reported by timed runs when counting allocations.
+    if timings.data.iter().any(|t| {
+        [t.part_1, t.part_2]
+            .iter()
+            .flatten()
+            .any(|p| p.memory.is_some())
+    }) {
+        print_memory(&timings);
+    }
+
+    let changes = stored_timings.compare(&timings);
+    let regressions: Vec<&TimingChange> = changes
+        .iter()
+        .filter(|change| change.is_regression(options.threshold))
+        .collect();
+
+    if options.compare {
+        print_changes(&changes, options.threshold);
+    }
+
+    failed |= options.compare && !regressions.is_empty();
+
+    if options.store {
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
+
+        if !regressions.is_empty() && !options.force {
+            eprintln!(
+                "Refusing to store benchmarks: {} part(s) regressed by more than {}%. Pass `--force` to overwrite them anyway.",
+                regressions.len(),
+                options.threshold
+            );
+            failed = true;
+        } else {
+            let merged_timings = stored_timings.merge(&timings);
+            merged_timings.store_file(year).unwrap();
+
+            let mut history = TimingHistory::read_from_file(year);
+            history.append(&timings, run_options.profile());
+            if let Err(e) = history.store_file(year) {
+                eprintln!("Failed to store timing history: {e}");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+
+            match readme_benchmarks::update(year) {
+                Ok(()) => {
+                    println!("Stored updated benchmarks.");
+                }
+                Err(e) => {
+                    eprintln!("Failed to store updated benchmarks: {e}");
+                }
             }
         }
     }
+
+    if let Some((format, path)) = &options.export {
+        // exports of several years are told apart by a `{year}` placeholder in the path.
+        let path = &PathBuf::from(year_path(&path.to_string_lossy(), year));
+        let merged_timings = stored_timings.merge(&timings);
+        match export(&merged_timings, *format, path) {
+            Ok(()) => println!("Exported benchmarks to {}.", path.display()),
+            Err(e) => {
+                eprintln!("Failed to export benchmarks to {}: {e}", path.display());
+                failed = true;
+            }
+        }
+    }
+
+    !failed
+}
+
+/// Checks the benchmark tables of a year. Returns `false` if they are out of date.
+fn check_readme(year: u16) -> bool {
+    match readme_benchmarks::check(year) {
+        Ok(stale) if stale.is_empty() => {
+            println!("Benchmark tables are up to date.");
+            true
+        }
+        Ok(stale) => {
+            eprintln!(
+                "Benchmark tables are out of date in: {}. Run `cargo time --store` to update them.",
+                stale.join(", ")
+            );
+            false
+        }
+        Err(e) => {
+            eprintln!("Failed to check benchmark tables: {e}");
+            false
+        }
+    }
+}
+
+fn print_memory(timings: &Timings) {
+    println!("\n{ANSI_BOLD}Heap usage{ANSI_RESET}");
+    println!("----------");
+
+    for timing in &timings.data {
+        for part in [1, 2] {
+            let Some(PartTiming {
+                nanos,
+                memory: Some(memory),
+                ..
+            }) = timing.part(part)
+            else {
+                continue;
+            };
+
+            println!(
+                "Day {} Part {part}: {} {ANSI_ITALIC}(peak {}, {} allocated in {} allocations){ANSI_RESET}",
+                timing.day,
+                format_nanos(nanos),
+                format_bytes(memory.peak_bytes),
+                format_bytes(memory.total_bytes),
+                memory.allocations
+            );
+        }
+    }
+}
+
+fn print_changes(changes: &[TimingChange], threshold: f64) {
+    println!("\n{ANSI_BOLD}Comparison{ANSI_RESET}");
+    println!("----------");
+
+    if changes.is_empty() {
+        println!("No stored timings to compare against.");
+        return;
+    }
+
+    for change in changes {
+        let old = format_nanos(change.old_nanos);
+        let label = format!("Day {} Part {}", change.day, change.part);
+
+        let Some(percent) = change.percent() else {
+            println!("{label}: {old} → {ANSI_RED}✖{ANSI_RESET}");
+            continue;
+        };
+
+        let new = format_nanos(change.new_nanos.unwrap_or_default());
+
+        let color = if percent > threshold {
+            ANSI_RED
+        } else if percent < -threshold {
+            ANSI_GREEN
+        } else {
+            ANSI_ITALIC
+        };
+
+        println!("{label}: {old} → {new} ({color}{percent:+.2}%{ANSI_RESET})");
+    }
+}
+
+fn print_history(year: u16, day: Day) {
+    let history = TimingHistory::read_from_file(year);
+    let records = history.for_day(day);
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    if records.is_empty() {
+        println!("No stored timings.");
+        return;
+    }
+
+    println!("| Date             | Commit  | Profile | Part 1    | Part 2    |");
+    println!("| :--------------- | :------ | :------ | --------: | --------: |");
+
+    for record in &records {
+        println!(
+            "| {} | {:<7} | {:<7} | {:>9} | {:>9} |",
+            format_timestamp(record.timestamp),
+            record.commit.as_deref().unwrap_or("-"),
+            record.profile,
+            record
+                .timing
+                .part_nanos(1)
+                .map_or_else(|| "-".into(), format_nanos),
+            record
+                .timing
+                .part_nanos(2)
+                .map_or_else(|| "-".into(), format_nanos),
+        );
+    }
+
+    println!();
+
+    for best in history.best(day) {
+        println!(
+            "{ANSI_BOLD}Best Part {}:{ANSI_RESET} {} {ANSI_ITALIC}({}, {}){ANSI_RESET}",
+            best.part,
+            format_nanos(best.nanos),
+            format_timestamp(best.record.timestamp),
+            best.record.commit.as_deref().unwrap_or("unknown commit"),
+        );
+    }
 }
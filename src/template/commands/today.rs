@@ -0,0 +1,93 @@
+use std::{fs, io::Write, path::Path, process, thread, time::Duration};
+
+use chrono::{DateTime, Local, Utc};
+
+use crate::template::{
+    commands::{download, read, scaffold},
+    Day, Event, Puzzle,
+};
+
+pub fn handle(year: u16, wait: bool) {
+    let day = if wait {
+        wait_for_next_day(year)
+    } else {
+        Day::today(year).unwrap_or_else(|| {
+            eprintln!(
+                "`today` command can only be run on the {} days of the {year} event in december. \
+                Pass `--wait` to wait for the next puzzle, or use `scaffold` with a specific day.",
+                Event::read(year).days
+            );
+            process::exit(1)
+        })
+    };
+
+    let puzzle = Puzzle::new(year, day);
+
+    // steps that were done before, e.g. when running `today` twice, are skipped.
+    if Path::new(&puzzle.bin_path()).exists() {
+        println!("Module file \"{}\" already exists.", puzzle.bin_path());
+    } else {
+        scaffold::handle(puzzle, false);
+    }
+
+    let input_path = puzzle.data_path("inputs", "txt");
+    if fs::metadata(&input_path).is_ok_and(|metadata| metadata.len() > 0) {
+        println!("Input \"{input_path}\" was already downloaded.");
+    } else {
+        download::handle(puzzle);
+    }
+
+    read::handle(puzzle);
+}
+
+/// Waits until the next puzzle of the event of a year unlocks, printing a countdown.
+fn wait_for_next_day(year: u16) -> Day {
+    let Some((day, unlock_time)) = Day::next_unlock(year) else {
+        eprintln!(
+            "All puzzles of {year} are unlocked already. Please use `scaffold` with a specific day."
+        );
+        process::exit(1)
+    };
+
+    println!(
+        "Day {day} unlocks at {}.",
+        unlock_time
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S")
+    );
+
+    while let Some(remaining) = time_until(unlock_time) {
+        print!("\r⏳ Day {day} unlocks in {}", format_countdown(remaining));
+        let _ = std::io::stdout().flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    // NOTE: `\x1b[2K` clears the line of the countdown.
+    println!("\r\x1b[2K🎄 Day {day} is unlocked!");
+    day
+}
+
+/// Returns the time left until a point in time, `None` once it has passed.
+fn time_until(time: DateTime<Utc>) -> Option<Duration> {
+    (time - Utc::now())
+        .to_std()
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// Formats a countdown, e.g. `01:02:03` or `3d 01:02:03`.
+fn format_countdown(remaining: Duration) -> String {
+    // NOTE: round up, so the countdown reads `00:00:01` until the puzzle unlocks.
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+
+    match secs / 86400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
@@ -0,0 +1,7 @@
+use std::time::Duration;
+
+use crate::template::commands::all;
+
+pub fn handle(years: &[u16], is_release: bool, jobs: usize, timeout: Option<Duration>) {
+    all::handle(years, is_release, true, jobs, timeout);
+}
@@ -2,9 +2,12 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Event;
+
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
 
+/// Puzzles unlock at midnight in the timezone of the server, which is UTC-5.
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
@@ -45,15 +48,35 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
+    /// Returns the current day if it's part of the event of a year, `None` otherwise.
+    /// The day is determined in the timezone of the server, regardless of the local timezone.
+    pub fn today(year: u16) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
+        if today.year() != i32::from(year) || today.month() != 12 {
+            return None;
         }
+
+        let day = Self::new(u8::try_from(today.day()).ok()?)?;
+        Event::read(year).contains(day).then_some(day)
+    }
+
+    /// Returns the next day of the event of a year whose puzzle is not unlocked yet, along with its unlock time.
+    pub fn next_unlock(year: u16) -> Option<(Self, DateTime<Utc>)> {
+        let now = Utc::now();
+        Event::read(year)
+            .days()
+            .filter_map(|day| Some((day, day.unlock_time(year)?)))
+            .find(|(_, unlock_time)| *unlock_time > now)
+    }
+
+    /// Returns the time the puzzle of this day unlocks in a year.
+    pub fn unlock_time(self, year: u16) -> Option<DateTime<Utc>> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let midnight = NaiveDate::from_ymd_opt(i32::from(year), 12, u32::from(self.0))?
+            .and_hms_opt(0, 0, 0)?;
+        let unlock_time = offset.from_local_datetime(&midnight).single()?;
+        Some(unlock_time.with_timezone(&Utc))
     }
 }
 
@@ -100,20 +123,30 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of the event of a year, e.g. from the 1st to the 12th in 2025.
+pub fn all_days(year: u16) -> AllDays {
+    Event::read(year).days()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to a last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
+    /// Yields every day from the 1st to the 25th.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::until(Day(25))
+    }
+
+    /// Yields every day from the 1st to the last day.
+    pub fn until(last: Day) -> Self {
+        Self {
+            current: 1,
+            last: last.0,
+        }
     }
 }
 
@@ -121,10 +154,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -154,11 +187,11 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{AllDays, Day};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::new();
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +220,14 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_until_last_day() {
+        let days: Vec<Day> = AllDays::until(Day(12)).collect();
+
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
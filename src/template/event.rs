@@ -0,0 +1,174 @@
+/// Module that describes the days of the event of a year, read from the optional `data/<year>/event.json`.
+use std::{collections::HashMap, fs, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{year_path, AllDays, Day};
+
+static EVENT_FILE_PATH: &str = "./data/{year}/event.json";
+
+/// Describes the days of the event of a year.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// Number of days of the event.
+    pub days: u8,
+    /// The last day only has a single part, its second star is awarded for completing all others.
+    pub single_part_last_day: bool,
+    /// Number of parts of single days, overriding the rule for the last day.
+    pub parts: HashMap<Day, u8>,
+}
+
+impl Event {
+    /// Returns the event of a year if it is not configured: 25 days until 2024, 12 days since 2025.
+    pub fn default_for(year: u16) -> Self {
+        Self {
+            days: if year < 2025 { 25 } else { 12 },
+            single_part_last_day: true,
+            parts: HashMap::new(),
+        }
+    }
+
+    /// Read the event of a year from its config file. If not present, returns the default event.
+    pub fn read(year: u16) -> Self {
+        let path = year_path(EVENT_FILE_PATH, year);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Self::default_for(year);
+        };
+
+        Event::parse(&contents, year).unwrap_or_else(|e| {
+            eprintln!("Ignoring invalid {path}: {e}");
+            Self::default_for(year)
+        })
+    }
+
+    /// Returns the last day of the event.
+    pub fn last_day(&self) -> Day {
+        Day::__new_unchecked(self.days)
+    }
+
+    /// Returns `true` if the day is part of the event.
+    pub fn contains(&self, day: Day) -> bool {
+        day <= self.days
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(&self) -> AllDays {
+        AllDays::until(self.last_day())
+    }
+
+    /// Returns the number of parts of a day.
+    pub fn parts(&self, day: Day) -> u8 {
+        if let Some(parts) = self.parts.get(&day) {
+            *parts
+        } else if self.single_part_last_day && day == self.last_day() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Parses the config of a year. Keys that are not present fall back to the defaults of the year.
+    fn parse(contents: &str, year: u16) -> Result<Self, String> {
+        let json = JsonValue::from_str(contents).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut event = Self::default_for(year);
+
+        if let Some(v) = json.get("days") {
+            event.days = v
+                .get::<f64>()
+                .filter(|days| days.fract() == 0.0 && (1.0..=25.0).contains(*days))
+                .map(|days| *days as u8)
+                .ok_or("Expected days to be a number between 1 and 25.")?;
+        }
+
+        if let Some(v) = json.get("single_part_last_day") {
+            event.single_part_last_day = *v
+                .get::<bool>()
+                .ok_or("Expected single_part_last_day to be a boolean.")?;
+        }
+
+        if let Some(v) = json.get("parts") {
+            event.parts = v
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected parts to be an object.")?
+                .iter()
+                .map(|(day, parts)| {
+                    let day = day
+                        .parse::<Day>()
+                        .map_err(|_| format!("Expected parts.{day} to be keyed by a day."))?;
+                    let parts = parts
+                        .get::<f64>()
+                        .filter(|parts| **parts == 1.0 || **parts == 2.0)
+                        .ok_or_else(|| format!("Expected parts.{day} to be 1 or 2."))?;
+                    Ok((day, *parts as u8))
+                })
+                .collect::<Result<_, String>>()?;
+        }
+
+        Ok(event)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::day;
+
+    use super::Event;
+
+    #[test]
+    fn defaults_to_event_length_of_year() {
+        assert_eq!(Event::default_for(2024).days, 25);
+        assert_eq!(Event::default_for(2025).days, 12);
+        assert_eq!(Event::default_for(2025).days().last(), Some(day!(12)));
+    }
+
+    #[test]
+    fn counts_parts_of_days() {
+        let event = Event::default_for(2025);
+        assert_eq!(event.parts(day!(11)), 2);
+        assert_eq!(event.parts(day!(12)), 1);
+        assert!(event.contains(day!(12)));
+        assert!(!event.contains(day!(13)));
+
+        let mut event = Event {
+            single_part_last_day: false,
+            ..event
+        };
+        assert_eq!(event.parts(day!(12)), 2);
+
+        event.parts.insert(day!(5), 1);
+        assert_eq!(event.parts(day!(5)), 1);
+    }
+
+    #[test]
+    fn parses_event_config() {
+        assert_eq!(
+            Event::parse(r#"{ "days": 25 }"#, 2025),
+            Ok(Event {
+                days: 25,
+                ..Event::default_for(2025)
+            })
+        );
+        assert_eq!(
+            Event::parse(r#"{ "single_part_last_day": false }"#, 2024),
+            Ok(Event {
+                single_part_last_day: false,
+                ..Event::default_for(2024)
+            })
+        );
+        assert_eq!(
+            Event::parse(r#"{ "parts": { "12": 2, "03": 1 } }"#, 2025).map(|e| e.parts),
+            Ok(HashMap::from([(day!(12), 2), (day!(3), 1)]))
+        );
+        assert!(Event::parse(r#"{ "parts": { "12": 3 } }"#, 2025).is_err());
+        assert!(Event::parse(r#"{ "days": 26 }"#, 2025).is_err());
+        assert!(Event::parse(r#"{ "days": 1.5 }"#, 2025).is_err());
+    }
+}
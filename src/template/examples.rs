@@ -0,0 +1,217 @@
+/// Module that reads example manifests, which list example inputs of a day along with their expected answers.
+use std::{collections::HashMap, env, fs, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day, Puzzle};
+
+/// A single example input, along with the answers it should produce.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExampleCase {
+    pub name: String,
+    /// File in `data/YYYY/examples` that contains the input.
+    pub file: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    /// Extra parameters of the case, e.g. constants that differ between example and puzzle input.
+    pub params: Vec<(String, String)>,
+}
+
+impl ExampleCase {
+    /// Returns the expected answer for a part, if any.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Reads the input of the case from the examples of a year.
+    #[must_use]
+    pub fn read_input(&self, year: u16) -> String {
+        let cwd = env::current_dir().unwrap();
+        let filepath = cwd.join(data_dir(year)).join("examples").join(&self.file);
+        fs::read_to_string(filepath).expect("could not open example file")
+    }
+}
+
+/// Represents the contents of `data/YYYY/examples/DD.json`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExampleManifest {
+    pub cases: Vec<ExampleCase>,
+}
+
+impl ExampleManifest {
+    /// Reads the manifest of a puzzle.
+    pub fn read(puzzle: Puzzle) -> Result<Self, String> {
+        let path = puzzle.data_path("examples", "json");
+        let contents =
+            fs::read_to_string(&path).map_err(|e| format!("Could not read {path}: {e}"))?;
+        Self::parse(&contents, puzzle.day).map_err(|e| format!("Could not parse {path}: {e}"))
+    }
+
+    /// Returns the case with the given name, if any.
+    pub fn case(&self, name: &str) -> Option<&ExampleCase> {
+        self.cases.iter().find(|case| case.name == name)
+    }
+
+    /// Parses a manifest. Cases without a `file` read `DD.txt`.
+    fn parse(contents: &str, day: Day) -> Result<Self, String> {
+        let json = JsonValue::from_str(contents).or(Err("not valid JSON file."))?;
+
+        let json_cases = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("cases")
+            .ok_or("expected JSON document to have key `cases`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.cases` to be an array.")?;
+
+        let cases = json_cases
+            .iter()
+            .enumerate()
+            .map(|(i, case)| parse_case(case, i, day))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ExampleManifest { cases })
+    }
+}
+
+/// Largest integer that is parsed exactly, as JSON numbers are parsed as `f64`.
+const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_991_f64;
+
+/// Reads a string or an integer as a string, e.g. to allow both `"42"` and `42` as answers.
+/// Other numbers are rejected, since their `f64` value may differ from what was written.
+fn value_to_string(value: &JsonValue, key: &str) -> Result<String, String> {
+    match value {
+        JsonValue::String(s) => Ok(s.clone()),
+        JsonValue::Number(n) if n.fract() == 0_f64 && n.abs() <= MAX_EXACT_INTEGER => {
+            Ok(format!("{n:.0}"))
+        }
+        JsonValue::Number(n) => Err(format!(
+            "case.{key} is {n}, which is not an integer below 2^53. Quote it as a string instead."
+        )),
+        _ => Err(format!("Expected case.{key} to be a string or a number.")),
+    }
+}
+
+fn parse_case(value: &JsonValue, index: usize, day: Day) -> Result<ExampleCase, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected case to be a JSON object.")?;
+
+    let name = match json.get("name") {
+        Some(v) => v
+            .get::<String>()
+            .ok_or("Expected case.name to be a string.")?
+            .clone(),
+        None => format!("case_{}", index + 1),
+    };
+
+    let file = match json.get("file") {
+        Some(v) => v
+            .get::<String>()
+            .ok_or("Expected case.file to be a string.")?
+            .clone(),
+        None => format!("{day}.txt"),
+    };
+
+    let answer = |key: &str| -> Result<Option<String>, String> {
+        match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => value_to_string(v, key).map(Some),
+        }
+    };
+
+    let mut params: Vec<(String, String)> = match json.get("params") {
+        Some(v) => v
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected case.params to be an object.")?
+            .iter()
+            .map(|(key, value)| {
+                value_to_string(value, &format!("params.{key}")).map(|value| (key.clone(), value))
+            })
+            .collect::<Result<_, _>>()?,
+        None => vec![],
+    };
+    params.sort();
+
+    Ok(ExampleCase {
+        name,
+        file,
+        part_1: answer("part_1")?,
+        part_2: answer("part_2")?,
+        params,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{ExampleCase, ExampleManifest};
+
+    #[test]
+    fn handles_example_manifests() {
+        let json = r#"{ "cases": [{ "name": "small", "part_1": "42", "part_2": 1337 }, { "file": "01-2.txt", "part_2": null, "params": { "steps": 10, "mode": "fast" } }] }"#;
+        let manifest = ExampleManifest::parse(json, day!(1)).unwrap();
+        assert_eq!(
+            manifest.cases,
+            vec![
+                ExampleCase {
+                    name: "small".into(),
+                    file: "01.txt".into(),
+                    part_1: Some("42".into()),
+                    part_2: Some("1337".into()),
+                    params: vec![],
+                },
+                ExampleCase {
+                    name: "case_2".into(),
+                    file: "01-2.txt".into(),
+                    part_1: None,
+                    part_2: None,
+                    params: vec![
+                        ("mode".into(), "fast".into()),
+                        ("steps".into(), "10".into())
+                    ],
+                },
+            ]
+        );
+        assert_eq!(manifest.case("small").unwrap().expected(2), Some("1337"));
+        assert!(manifest.case("large").is_none());
+    }
+
+    #[test]
+    fn rejects_inexact_numbers() {
+        let parse = |answer: &str| {
+            let json = format!(r#"{{ "cases": [{{ "part_1": {answer} }}] }}"#);
+            ExampleManifest::parse(&json, day!(1)).map(|m| m.cases[0].part_1.clone())
+        };
+
+        assert_eq!(
+            parse("9007199254740991"),
+            Ok(Some("9007199254740991".into()))
+        );
+        assert_eq!(parse("-12"), Ok(Some("-12".into())));
+        assert_eq!(
+            parse(r#""9007199254740993""#),
+            Ok(Some("9007199254740993".into()))
+        );
+        assert!(parse("9007199254740992").is_err());
+        assert!(parse("9007199254740993").is_err());
+        assert!(parse("12345678901234567890").is_err());
+        assert!(parse("1.5").is_err());
+
+        let json = r#"{ "cases": [{ "params": { "rate": 0.5 } }] }"#;
+        assert!(ExampleManifest::parse(json, day!(1)).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_cases() {
+        let json = r#"{ "cases": [{ "part_1": true }] }"#;
+        ExampleManifest::parse(json, day!(1)).unwrap();
+    }
+}
@@ -1,68 +1,198 @@
 use std::{env, fs};
 
+#[cfg(feature = "count-allocations")]
+pub mod allocator;
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod params;
 pub mod runner;
 
 pub use day::*;
+pub use event::Event;
+pub use params::param;
+pub use puzzle::*;
 
+mod answers;
 mod day;
+mod event;
+#[cfg(feature = "profile")]
+mod profiler;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_config;
+mod readme_progress;
 mod run_multi;
+mod submissions;
+mod timing_history;
 mod timings;
+mod timings_export;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string, e.g. `data/2025/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(data_dir(puzzle.year))
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `PUZZLE` and sets up the input and runner for each part.
 ///
+/// The year of the puzzle is read from the name of the binary, e.g. `2025` for `src/bin/2025_01.rs`.
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Pass `parse = <function>` last to parse the input in a separate step that is timed on its own.
+/// The parts then take a reference to the result of the parse function instead of the input.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2] $(; $parse)?);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 1 $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $day, [part_one, 1] $(; $parse)?);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 2 $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $day, [part_two, 2] $(; $parse)?);
     };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@setup $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            if has_flag("--examples") {
+                run_examples(PUZZLE, &[
+                    $( ($part, &|input: &str| $func(input).outcome()) ),*
+                ]);
+                return;
+            }
+
+            let input = read_input(PUZZLE);
+            $(
+                if should_run_part($part) {
+                    run_part($func, &input, PUZZLE, $part);
+                }
+            )*
+        }
+    };
+    (@impl $day:expr, $( [$func:expr, $part:expr] )*; $parse:expr) => {
+        $crate::solution!(@setup $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            if has_flag("--examples") {
+                run_examples(PUZZLE, &[
+                    $( ($part, &|input: &str| $func(&$parse(input)).outcome()) ),*
+                ]);
+                return;
+            }
+
+            let input = read_input(PUZZLE);
+            $(
+                if should_run_part($part) {
+                    run_parsed_part($parse, $func, &input, PUZZLE, $part);
+                }
+            )*
+        }
+    };
+
+    (@setup $day:expr) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The puzzle of the current day, in the year the binary is named after.
+        const PUZZLE: $crate::template::Puzzle = $crate::template::Puzzle::new(
+            $crate::template::year_of_bin(env!("CARGO_BIN_NAME")),
+            DAY,
+        );
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+        #[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::allocator::CountingAllocator =
+            $crate::template::allocator::CountingAllocator;
+    };
+}
+
+/// Generates one test per case of the example manifest in `data/YYYY/examples/DD.json`.
+///
+/// Each case is compared against the expected answers of both parts. Pass `1;` or `2;` before the
+/// case names to only check a single part, e.g. when the other part is not solved yet.
+/// Solutions with a parse step pass it as well, e.g. `parse = parse;` or `1, parse = parse;`.
+#[macro_export]
+macro_rules! example_tests {
+    (parse = $parse:expr; $( $case:ident ),+ $(,)?) => {
+        $crate::example_tests!(@impl [part_one, 1] [part_two, 2]; $parse; $( $case ),+);
+    };
+    ($( $case:ident ),+ $(,)?) => {
+        $crate::example_tests!(@impl [part_one, 1] [part_two, 2]; std::convert::identity; $( $case ),+);
+    };
+    (1 $(, parse = $parse:expr)?; $( $case:ident ),+ $(,)?) => {
+        $crate::example_tests!(@impl [part_one, 1]; $crate::example_tests!(@parse $($parse)?); $( $case ),+);
+    };
+    (2 $(, parse = $parse:expr)?; $( $case:ident ),+ $(,)?) => {
+        $crate::example_tests!(@impl [part_two, 2]; $crate::example_tests!(@parse $($parse)?); $( $case ),+);
+    };
+
+    (@parse) => {
+        std::convert::identity
+    };
+    (@parse $parse:expr) => {
+        $parse
+    };
+
+    // without a parse step, parts are called with a reference to the input, which derefs to `&str`.
+    (@impl $( [$func:expr, $part:expr] )+; $parse:expr; $( $case:ident ),+) => {
+        fn check_example(name: &str) {
+            let manifest = $crate::template::examples::ExampleManifest::read(PUZZLE).unwrap();
+            let case = manifest
+                .case(name)
+                .unwrap_or_else(|| panic!("no example `{name}` in the manifest"));
+            let input = case.read_input(PUZZLE.year);
+            $(
+                if let Some(expected) = case.expected($part) {
+                    use $crate::template::runner::{read_letters, Outcome, PartResult};
+                    let outcome = $crate::template::params::with(case.params.clone(), || {
+                        $func(&$parse(input.as_str())).outcome()
+                    });
+                    match outcome {
+                        Outcome::Solved(answer) => {
+                            let answer = read_letters(&answer).unwrap_or(answer);
+                            assert_eq!(answer, expected, "part {}", $part);
+                        }
+                        outcome => panic!("part {} has no answer: {outcome:?}", $part),
+                    }
+                }
+            )+
         }
+
+        $(
+            #[test]
+            fn $case() {
+                check_example(stringify!($case));
+            }
+        )+
     };
 }
@@ -0,0 +1,109 @@
+/// Module that passes named parameters to solution parts.
+/// A part reads a parameter with [`param`], which falls back to the default for the puzzle input if it was not set.
+/// Parameters are set by the runner from `--param name=value` and by example cases from their `params`.
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    str::FromStr,
+    sync::{Mutex, OnceLock, PoisonError, RwLock},
+};
+
+/// Parameters of the process, so that threads spawned by a part read the same values.
+static PARAMS: OnceLock<RwLock<HashMap<String, String>>> = OnceLock::new();
+
+/// Serializes [`with`], as tests of example cases run in parallel.
+static SCOPE: Mutex<()> = Mutex::new(());
+
+fn params() -> &'static RwLock<HashMap<String, String>> {
+    PARAMS.get_or_init(RwLock::default)
+}
+
+/// Replace the parameters of the process.
+pub fn set(params: Vec<(String, String)>) {
+    *self::params()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = params.into_iter().collect();
+}
+
+/// Run `f` with the given parameters. Other calls of `with` wait until `f` returns.
+pub fn with<R>(params: Vec<(String, String)>, f: impl FnOnce() -> R) -> R {
+    let _guard = SCOPE.lock().unwrap_or_else(PoisonError::into_inner);
+    set(params);
+    f()
+}
+
+/// Read a parameter, or return `default` if it was not set.
+///
+/// # Panics
+/// Panics if the parameter was set to a value that can not be parsed as `T`.
+pub fn param<T: FromStr>(name: &str, default: T) -> T
+where
+    T::Err: Debug,
+{
+    params()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .map_or(default, |value| {
+            value
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid value `{value}` for param `{name}`: {e:?}"))
+        })
+}
+
+/// Parse a parameter passed as `name=value`.
+pub fn parse(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!(
+            "expected param in the format `name=value`, got `{s}`."
+        )),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::thread;
+
+    use super::{param, parse, with};
+
+    #[test]
+    fn reads_params_with_defaults() {
+        with(vec![("connections".into(), "10".into())], || {
+            assert_eq!(param("connections", 1000_usize), 10);
+            assert_eq!(param("steps", 64_u32), 64);
+        });
+        with(vec![], || {
+            assert_eq!(param("connections", 1000_usize), 1000)
+        });
+    }
+
+    #[test]
+    fn shares_params_between_threads() {
+        with(vec![("steps".into(), "6".into())], || {
+            let steps = thread::spawn(|| param("steps", 64_u32)).join().unwrap();
+            assert_eq!(steps, 6);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_values() {
+        with(vec![("connections".into(), "ten".into())], || {
+            param("connections", 1000_usize)
+        });
+    }
+
+    #[test]
+    fn parses_params() {
+        assert_eq!(
+            parse("connections=10"),
+            Ok(("connections".into(), "10".into()))
+        );
+        assert_eq!(parse("name=a=b"), Ok(("name".into(), "a=b".into())));
+        assert!(parse("connections").is_err());
+        assert!(parse("=10").is_err());
+    }
+}
@@ -0,0 +1,66 @@
+/// Module that samples a solution part with `pprof` and renders the result as a flamegraph.
+/// Sampling uses a timer signal, so it does not depend on `perf` or other system tools.
+use std::fs::{self, File};
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::template::{year_path, Puzzle, ANSI_ITALIC, ANSI_RESET};
+
+static PROFILES_DIR: &str = "./data/{year}/profiles";
+
+/// Samples per second.
+const FREQUENCY: i32 = 1000;
+
+/// The part is run repeatedly until this much time has passed, to collect enough samples.
+const MIN_DURATION: Duration = Duration::from_secs(3);
+
+/// Run a part in a loop while sampling it, then write a flamegraph to `data/YYYY/profiles/DD-partN.svg`.
+pub fn profile<I: Copy, T>(func: impl Fn(I) -> T, input: I, puzzle: Puzzle, part: u8) {
+    let dir = year_path(PROFILES_DIR, puzzle.year);
+    let path = PathBuf::from(&dir).join(format!("{}-part{part}.svg", puzzle.day));
+
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Failed to create {dir}: {e}");
+        return;
+    }
+
+    println!("Part {part}: {ANSI_ITALIC}profiling...{ANSI_RESET}");
+
+    let guard = match pprof::ProfilerGuardBuilder::default()
+        .frequency(FREQUENCY)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+    {
+        Ok(guard) => guard,
+        Err(e) => {
+            eprintln!("Failed to start profiler: {e}");
+            return;
+        }
+    };
+
+    let timer = Instant::now();
+    let mut iterations = 0;
+
+    while iterations == 0 || timer.elapsed() < MIN_DURATION {
+        black_box(func(black_box(input)));
+        iterations += 1;
+    }
+
+    let result = guard
+        .report()
+        .build()
+        .map_err(|e| e.to_string())
+        .and_then(|report| {
+            let file = File::create(&path).map_err(|e| e.to_string())?;
+            report.flamegraph(file).map_err(|e| e.to_string())
+        });
+
+    match result {
+        Ok(()) => println!(
+            "Part {part}: wrote flamegraph of {iterations} iteration(s) to {}.",
+            path.display()
+        ),
+        Err(e) => eprintln!("Failed to write flamegraph for part {part}: {e}"),
+    }
+}
@@ -0,0 +1,147 @@
+/// Module that identifies puzzles by year and day, and locates the files that belong to them.
+use std::{env, fmt::Display, fs};
+
+use crate::template::Day;
+
+/// Identifies the puzzle of a day in a given year.
+///
+/// # Display
+/// This value displays as the name of its binary, e.g. `2025_08`.
+///
+/// ```
+/// # use advent_of_code::template::{Day, Puzzle};
+/// let puzzle = Puzzle::new(2025, Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2025_08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: u16, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path of the solution binary, e.g. `./src/bin/2025_08.rs`.
+    #[must_use]
+    pub fn bin_path(&self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+
+    /// Path of a file of the puzzle in a data folder, e.g. `./data/2025/inputs/08.txt`.
+    #[must_use]
+    pub fn data_path(&self, folder: &str, extension: &str) -> String {
+        format!("{}/{folder}/{}.{extension}", data_dir(self.year), self.day)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Folder that holds the inputs, examples and stores of a year, e.g. `./data/2025`.
+#[must_use]
+pub fn data_dir(year: u16) -> String {
+    format!("./data/{year}")
+}
+
+/// Replaces the `{year}` placeholder of a path, e.g. `./data/{year}/answers.json`.
+#[must_use]
+pub fn year_path(path: &str, year: u16) -> String {
+    path.replace("{year}", &year.to_string())
+}
+
+/// Returns the year set in `AOC_YEAR`, which defaults to the value in `.cargo/config.toml`.
+#[must_use]
+pub fn current_year() -> Option<u16> {
+    env::var("AOC_YEAR").ok()?.parse().ok()
+}
+
+/// Returns every year that has at least one solution in `src/bin`, in ascending order.
+#[must_use]
+pub fn all_years() -> Vec<u16> {
+    let Ok(entries) = fs::read_dir("./src/bin") else {
+        return vec![];
+    };
+
+    let mut years: Vec<u16> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let (year, day) = name.strip_suffix(".rs")?.split_once('_')?;
+            day.parse::<Day>().ok()?;
+            year.parse().ok()
+        })
+        .collect();
+
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+/// Reads the year from the name of a solution binary in a const context, e.g. `2025` for `2025_08`.
+///
+/// # Panics
+/// Panics if the name does not start with a four digit year followed by `_`.
+#[must_use]
+pub const fn year_of_bin(name: &str) -> u16 {
+    let bytes = name.as_bytes();
+    assert!(
+        bytes.len() > 5 && bytes[4] == b'_',
+        "solution binaries must be named `YYYY_DD`"
+    );
+
+    let mut year = 0;
+    let mut i = 0;
+    while i < 4 {
+        assert!(
+            bytes[i].is_ascii_digit(),
+            "solution binaries must be named `YYYY_DD`"
+        );
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    year
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{year_of_bin, year_path, Puzzle};
+
+    #[test]
+    fn locates_puzzle_files() {
+        let puzzle = Puzzle::new(2024, day!(3));
+        assert_eq!(puzzle.to_string(), "2024_03");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2024_03.rs");
+        assert_eq!(
+            puzzle.data_path("inputs", "txt"),
+            "./data/2024/inputs/03.txt"
+        );
+        assert_eq!(
+            year_path("./data/{year}/answers.json", 2024),
+            "./data/2024/answers.json"
+        );
+    }
+
+    #[test]
+    fn reads_year_of_bin() {
+        const YEAR: u16 = year_of_bin("2015_25");
+        assert_eq!(YEAR, 2015);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_bins_without_year() {
+        year_of_bin("01");
+    }
+}
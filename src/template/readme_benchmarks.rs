@@ -1,19 +1,28 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::readme_config::{BarStyle, Column, ReadmeConfig, SortOrder, TableConfig};
+use crate::template::timings::{format_bytes, format_nanos, Timing, Timings};
+use crate::template::{year_path, Puzzle};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -21,106 +30,337 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
+}
+
+/// Returns the marker of a table before markers were scoped to a year, e.g. `<!--- benchmarking table:release --->`.
+fn unscoped_marker(name: Option<&str>) -> String {
+    match name {
+        Some(name) => MARKER.replace(" --->", &format!(":{name} --->")),
+        None => MARKER.into(),
+    }
+}
+
+/// Returns the marker of a table of a year, e.g. `<!--- benchmarking table:release:2025 --->` for `release`.
+fn marker(name: Option<&str>, year: u16) -> String {
+    year_marker(&unscoped_marker(name), year)
+}
+
+/// Scopes a marker to a year, so that the tables of several years can live in the same file.
+pub fn year_marker(marker: &str, year: u16) -> String {
+    marker.replace(" --->", &format!(":{year} --->"))
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Locates the table of a year. If the readme still has the marker that is not scoped to a year,
+/// the error names the marker to rename it to.
+pub fn locate_year_table(
+    readme: &str,
+    marker: &str,
+    unscoped_marker: &str,
+) -> Result<TablePosition, Error> {
+    locate_table(readme, marker).map_err(|e| match e {
+        Error::Parser(e) if readme.contains(unscoped_marker) => Error::Parser(format!(
+            "{e} Markers are scoped to a year, rename `{unscoped_marker}` to `{marker}`."
+        )),
+        e => e,
+    })
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
         .first()
         .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find table start position.")))?;
 
     let pos_end = matches
         .last()
         .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find table end position.")))?;
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    year: u16,
+    mut timings: Timings,
+    total_millis: f64,
+    config: &TableConfig,
+) -> String {
+    let header = format!("{prefix} {}", config.title);
+
+    let titles: Vec<String> = std::iter::once("Day".to_string())
+        .chain(config.columns.iter().map(|column| column_title(*column)))
+        .collect();
+
+    // NOTE: the alignment row keeps the trailing double space of tables rendered by earlier versions,
+    // so existing readmes are not reported as out of date.
+    let alignments = format!("| {}  |", vec![":---:"; titles.len()].join(" | "));
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format_row(&titles),
+        alignments,
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let total_nanos: f64 = timings.data.iter().map(|t| t.total_nanos).sum();
+    let slowest_nanos = timings
+        .data
+        .iter()
+        .map(|t| t.total_nanos)
+        .fold(0_f64, f64::max);
+
+    match config.sort {
+        SortOrder::Day => timings.data.sort_by_key(|t| t.day),
+        SortOrder::Fastest => timings
+            .data
+            .sort_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos)),
+        SortOrder::Slowest => timings
+            .data
+            .sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos)),
+    }
+
+    for timing in &timings.data {
+        let path = Puzzle::new(year, timing.day).bin_path();
+
+        let mut cells: Vec<String> = vec![format!("[Day {}]({})", timing.day.into_inner(), path)];
+        cells.extend(
+            config
+                .columns
+                .iter()
+                .map(|column| format_cell(*column, timing, total_nanos, slowest_nanos, config)),
+        );
+
+        #[allow(clippy::float_cmp)]
+        if config.highlight_slowest && slowest_nanos > 0_f64 && timing.total_nanos == slowest_nanos
+        {
+            cells = cells
+                .into_iter()
+                .map(|cell| format!("**{cell}**"))
+                .collect();
+        }
+
+        lines.push(format_row(&cells));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn format_row(cells: &[String]) -> String {
+    format!("| {} |", cells.join(" | "))
+}
+
+fn column_title(column: Column) -> String {
+    match column {
+        Column::Part(part) => format!("Part {part}"),
+        Column::PartMin(part) => format!("Part {part} (min)"),
+        Column::PartMedian(part) => format!("Part {part} (median)"),
+        Column::PartParse(part) => format!("Part {part} (parse)"),
+        Column::PartSamples(part) => format!("Part {part} (samples)"),
+        Column::PartPeakMemory(part) => format!("Part {part} (peak memory)"),
+        Column::PartTotalMemory(part) => format!("Part {part} (allocated)"),
+        Column::PartAllocations(part) => format!("Part {part} (allocations)"),
+        Column::Total => "Total".into(),
+        Column::Share => "Share".into(),
+        Column::Bar => String::new(),
+    }
+}
+
+fn format_cell(
+    column: Column,
+    timing: &Timing,
+    total_nanos: f64,
+    slowest_nanos: f64,
+    config: &TableConfig,
+) -> String {
+    let format_code = |nanos: Option<f64>| format!("`{}`", nanos.map_or("-".into(), format_nanos));
+    let format_memory =
+        |bytes: Option<u64>| format!("`{}`", bytes.map_or("-".into(), format_bytes));
+    let memory = |part: u8| timing.part(part).and_then(|t| t.memory);
+
+    match column {
+        Column::Part(part) => format_code(timing.part_nanos(part)),
+        Column::PartMin(part) => format_code(timing.part(part).and_then(|t| t.min_nanos)),
+        Column::PartMedian(part) => format_code(timing.part(part).and_then(|t| t.median_nanos)),
+        Column::PartParse(part) => format_code(timing.part(part).and_then(|t| t.parse_nanos)),
+        Column::PartSamples(part) => timing
+            .part(part)
+            .and_then(|t| t.samples)
+            .map_or("-".into(), |samples| samples.to_string()),
+        Column::PartPeakMemory(part) => format_memory(memory(part).map(|m| m.peak_bytes)),
+        Column::PartTotalMemory(part) => format_memory(memory(part).map(|m| m.total_bytes)),
+        Column::PartAllocations(part) => {
+            memory(part).map_or("-".into(), |m| m.allocations.to_string())
+        }
+        Column::Total => format_code(Some(timing.total_nanos)),
+        Column::Share => {
+            if total_nanos > 0_f64 {
+                format!("{:.1}%", timing.total_nanos / total_nanos * 100_f64)
+            } else {
+                "-".into()
+            }
+        }
+        Column::Bar => {
+            if slowest_nanos > 0_f64 {
+                format_bar(
+                    timing.total_nanos / slowest_nanos,
+                    config.bar_width,
+                    config.bar_style,
+                )
+            } else {
+                String::new()
+            }
+        }
+    }
+}
+
+/// Draws a horizontal bar that fills `ratio` of `width` characters.
+fn format_bar(ratio: f64, width: usize, style: BarStyle) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let filled = ratio.clamp(0_f64, 1_f64) * width as f64;
+
+    match style {
+        BarStyle::Ascii => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let count = filled.round() as usize;
+            "#".repeat(count)
+        }
+        BarStyle::Unicode => {
+            const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let eighths = (filled * 8_f64).round() as usize;
+            format!("{}{}", "█".repeat(eighths / 8), EIGHTHS[eighths % 8])
+        }
+    }
+}
+
+fn update_content(
+    s: &mut String,
+    year: u16,
+    timings: Timings,
+    total_millis: f64,
+    config: &TableConfig,
+) -> Result<(), Error> {
+    let marker = marker(config.name.as_deref(), year);
+    let positions = locate_year_table(s, &marker, &unscoped_marker(config.name.as_deref()))?;
+    let table = construct_table("##", &marker, year, timings, total_millis, config);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+/// The contents of a target file before and after rendering its tables.
+struct RenderedFile {
+    path: String,
+    current: String,
+    rendered: String,
+}
+
+/// Renders every configured table of a year from its stored timings, grouped by target file.
+fn render_files(year: u16) -> Result<Vec<RenderedFile>, Error> {
+    let config = ReadmeConfig::read_from_file();
+    let mut files: Vec<RenderedFile> = vec![];
+
+    for table in &config.benchmarks {
+        let path = year_path(&table.file, year);
+        let index = match files.iter().position(|f| f.path == path) {
+            Some(index) => index,
+            None => {
+                let current = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+                files.push(RenderedFile {
+                    path,
+                    rendered: current.clone(),
+                    current,
+                });
+                files.len() - 1
+            }
+        };
+
+        let timings = Timings::read_from_path(&year_path(&table.timings, year));
+        let total_millis = timings.total_millis();
+        update_content(
+            &mut files[index].rendered,
+            year,
+            timings,
+            total_millis,
+            table,
+        )?;
+    }
+
+    Ok(files)
+}
+
+/// Renders every configured table of a year and writes the files that changed.
+pub fn update(year: u16) -> Result<(), Error> {
+    for file in render_files(year)? {
+        if file.rendered != file.current {
+            fs::write(&file.path, &file.rendered)?;
+        }
+    }
     Ok(())
 }
 
+/// Returns the paths of files whose tables are out of date with the stored timings of a year.
+pub fn check(year: u16) -> Result<Vec<String>, Error> {
+    Ok(render_files(year)?
+        .into_iter()
+        .filter(|file| file.rendered != file.current)
+        .map(|file| file.path)
+        .collect())
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{format_bar, marker, update_content};
+    use crate::{
+        day,
+        template::readme_config::{BarStyle, Column, SortOrder, TableConfig},
+        template::timings::{test_helpers::part, PartStatus, PartTiming, Status, Timing, Timings},
+    };
+
+    static MARKER: &str = "<!--- benchmarking table:2025 --->";
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(10_000_000_f64)),
+                    part_2: Some(part(20_000_000_f64)),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(30_000_000_f64)),
+                    part_2: Some(part(40_000_000_f64)),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(40_000_000_f64)),
+                    part_2: Some(part(50_000_000_f64)),
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,28 +371,63 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,24 +435,161 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table:2025 --->",
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025_01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table:2025 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_configured_benchmarks() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let config = TableConfig {
+            title: "Timings".into(),
+            columns: vec![
+                Column::Total,
+                Column::PartSamples(1),
+                Column::PartParse(1),
+                Column::Share,
+                Column::Bar,
+            ],
+            sort: SortOrder::Slowest,
+            highlight_slowest: true,
+            bar_style: BarStyle::Ascii,
+            bar_width: 9,
+            ..TableConfig::default()
+        };
+        let mut timings = get_mock_timings();
+        timings.data[1].part_1 = Some(PartTiming {
+            parse_nanos: Some(2_000_000_f64),
+            ..part(30_000_000_f64)
+        });
+        update_content(&mut s, 2025, timings, 190.0, &config).unwrap();
+        let expected = [
+            "<!--- benchmarking table:2025 --->",
+            "## Timings",
+            "",
+            "| Day | Total | Part 1 (samples) | Part 1 (parse) | Share |  |",
+            "| :---: | :---: | :---: | :---: | :---: | :---:  |",
+            "| **[Day 4](./src/bin/2025_04.rs)** | **`90.0s`** | **10** | **`-`** | **47.4%** | **#########** |",
+            "| [Day 2](./src/bin/2025_02.rs) | `70.0s` | 10 | `2.0ms` | 36.8% | ####### |",
+            "| [Day 1](./src/bin/2025_01.rs) | `30.0s` | 10 | `-` | 15.8% | ### |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table:2025 --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_bars() {
+        assert_eq!(format_bar(1.0, 4, BarStyle::Unicode), "████");
+        assert_eq!(format_bar(0.5, 3, BarStyle::Unicode), "█▌");
+        assert_eq!(format_bar(0.0, 3, BarStyle::Unicode), "");
+        assert_eq!(format_bar(0.5, 4, BarStyle::Ascii), "##");
+    }
+
+    #[test]
+    fn updates_named_benchmarks() {
+        let mut s = format!(
+            "{}\n{}\n{}\n{}",
+            MARKER,
+            MARKER,
+            marker(Some("dhat"), 2025),
+            marker(Some("dhat"), 2025)
+        );
+        let config = TableConfig {
+            name: Some("dhat".into()),
+            title: "Heap".into(),
+            ..TableConfig::default()
+        };
+        update_content(&mut s, 2025, get_mock_timings(), 190.0, &config).unwrap();
+        update_content(&mut s, 2025, get_mock_timings(), 190.0, &config).unwrap();
+
+        assert!(s.starts_with(&format!("{}\n{}\n", MARKER, MARKER)));
+        assert!(s.contains("<!--- benchmarking table:dhat:2025 --->\n## Heap\n"));
+        assert!(s.ends_with("**Total: 190.00ms**\n<!--- benchmarking table:dhat:2025 --->"));
+        assert_eq!(s.matches("## Heap").count(), 1);
+    }
+
+    #[test]
+    fn updates_tables_of_several_years() {
+        let mut s = format!(
+            "{}\n{}\n{}\n{}",
+            marker(None, 2024),
+            marker(None, 2024),
+            MARKER,
+            MARKER
+        );
+        let mut timings_2024 = get_mock_timings();
+        timings_2024.data.truncate(1);
+
+        update_content(&mut s, 2024, timings_2024, 30.0, &TableConfig::default()).unwrap();
+        update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
+
+        let (table_2024, table_2025) = s.split_once(MARKER).unwrap();
+        assert!(table_2024.contains("[Day 1](./src/bin/2024_01.rs)"));
+        assert!(table_2024.contains("**Total: 30.00ms**"));
+        assert!(!table_2024.contains("2025_"));
+        assert!(table_2025.contains("[Day 4](./src/bin/2025_04.rs)"));
+        assert!(table_2025.contains("**Total: 190.00ms**"));
+        assert_eq!(s.matches("## Benchmarks").count(), 2);
+    }
+
+    #[test]
+    fn names_marker_to_rename_unscoped_markers_to() {
+        let mut s = "<!--- benchmarking table --->\n<!--- benchmarking table --->".to_string();
+        let e = update_content(
+            &mut s,
+            2025,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap_err();
+        assert!(e.to_string().ends_with(
+            "rename `<!--- benchmarking table --->` to `<!--- benchmarking table:2025 --->`."
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_named_marker_not_present() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let config = TableConfig {
+            name: Some("dhat".into()),
+            ..TableConfig::default()
+        };
+        update_content(&mut s, 2025, get_mock_timings(), 190.0, &config).unwrap();
+    }
 }
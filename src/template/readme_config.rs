@@ -0,0 +1,362 @@
+/// Module that reads the optional `data/readme.json` file which controls how README sections are rendered.
+use std::{collections::HashMap, fs, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::timings::TIMINGS_FILE_PATH;
+
+static README_CONFIG_FILE_PATH: &str = "./data/readme.json";
+
+/// A column of the benchmark table, next to the day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// Average time of a part.
+    Part(u8),
+    /// Fastest sample of a part.
+    PartMin(u8),
+    /// Median sample of a part.
+    PartMedian(u8),
+    /// Average time of the parse step of a part.
+    PartParse(u8),
+    /// Number of samples of a part.
+    PartSamples(u8),
+    /// Peak heap usage of a part.
+    PartPeakMemory(u8),
+    /// Bytes allocated by a part.
+    PartTotalMemory(u8),
+    /// Number of heap allocations of a part.
+    PartAllocations(u8),
+    /// Sum of the average times of both parts.
+    Total,
+    /// Share of the day in the total of all days.
+    Share,
+    /// Bar relative to the slowest day.
+    Bar,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let part_column = |suffix: &str| -> Option<u8> {
+            match s.strip_suffix(suffix)? {
+                "part_1" => Some(1),
+                "part_2" => Some(2),
+                _ => None,
+            }
+        };
+
+        if let Some(part) = part_column("") {
+            Ok(Column::Part(part))
+        } else if let Some(part) = part_column("_min") {
+            Ok(Column::PartMin(part))
+        } else if let Some(part) = part_column("_median") {
+            Ok(Column::PartMedian(part))
+        } else if let Some(part) = part_column("_parse") {
+            Ok(Column::PartParse(part))
+        } else if let Some(part) = part_column("_samples") {
+            Ok(Column::PartSamples(part))
+        } else if let Some(part) = part_column("_peak_memory") {
+            Ok(Column::PartPeakMemory(part))
+        } else if let Some(part) = part_column("_total_memory") {
+            Ok(Column::PartTotalMemory(part))
+        } else if let Some(part) = part_column("_allocations") {
+            Ok(Column::PartAllocations(part))
+        } else {
+            match s {
+                "total" => Ok(Column::Total),
+                "share" => Ok(Column::Share),
+                "bar" => Ok(Column::Bar),
+                _ => Err(format!("unknown column `{s}`.")),
+            }
+        }
+    }
+}
+
+/// Order of the rows in the benchmark table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Day,
+    Fastest,
+    Slowest,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortOrder::Day),
+            "fastest" => Ok(SortOrder::Fastest),
+            "slowest" => Ok(SortOrder::Slowest),
+            _ => Err(format!("unknown sort order `{s}`.")),
+        }
+    }
+}
+
+/// Characters used to draw the bar column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarStyle {
+    Ascii,
+    Unicode,
+}
+
+impl FromStr for BarStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(BarStyle::Ascii),
+            "unicode" => Ok(BarStyle::Unicode),
+            _ => Err(format!("unknown bar style `{s}`.")),
+        }
+    }
+}
+
+/// Controls where a benchmark table is rendered and how it looks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableConfig {
+    /// Name of the marker pair, e.g. `release` for `<!--- benchmarking table:release:<year> --->`.
+    /// `None` renders into the plain `<!--- benchmarking table:<year> --->` markers.
+    pub name: Option<String>,
+    /// File that contains the markers. `{year}` is replaced with the year of the table.
+    pub file: String,
+    /// Timings file the table is rendered from. `{year}` is replaced with the year of the table.
+    pub timings: String,
+    pub title: String,
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+    /// Render the row of the slowest day in bold.
+    pub highlight_slowest: bool,
+    pub bar_style: BarStyle,
+    /// Width of the bar of the slowest day in characters.
+    pub bar_width: usize,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            name: None,
+            file: "README.md".into(),
+            timings: TIMINGS_FILE_PATH.into(),
+            title: "Benchmarks".into(),
+            columns: vec![Column::Part(1), Column::Part(2)],
+            sort: SortOrder::Day,
+            highlight_slowest: false,
+            bar_style: BarStyle::Unicode,
+            bar_width: 10,
+        }
+    }
+}
+
+/// Represents the contents of `data/readme.json`. Every key is optional.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadmeConfig {
+    pub benchmarks: Vec<TableConfig>,
+}
+
+impl Default for ReadmeConfig {
+    fn default() -> Self {
+        Self {
+            benchmarks: vec![TableConfig::default()],
+        }
+    }
+}
+
+impl ReadmeConfig {
+    /// Read the config file. If not present, returns the default config.
+    pub fn read_from_file() -> Self {
+        let Ok(contents) = fs::read_to_string(README_CONFIG_FILE_PATH) else {
+            return Self::default();
+        };
+
+        ReadmeConfig::try_from(contents).unwrap_or_else(|e| {
+            eprintln!("Ignoring invalid {README_CONFIG_FILE_PATH}: {e}");
+            Self::default()
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for ReadmeConfig {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        // `benchmarks` is either a single table or a list of tables.
+        let benchmarks = match json.get("benchmarks") {
+            Some(JsonValue::Array(tables)) => tables
+                .iter()
+                .map(TableConfig::try_from)
+                .collect::<Result<_, _>>()?,
+            Some(v) => vec![TableConfig::try_from(v)?],
+            None => vec![TableConfig::default()],
+        };
+
+        Ok(ReadmeConfig { benchmarks })
+    }
+}
+
+impl TryFrom<&JsonValue> for TableConfig {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected benchmarks to be a JSON object.")?;
+
+        let mut config = TableConfig::default();
+
+        if let Some(v) = json.get("name") {
+            config.name = Some(
+                v.get::<String>()
+                    .ok_or("Expected benchmarks.name to be a string.")?
+                    .clone(),
+            );
+        }
+
+        if let Some(v) = json.get("file") {
+            config.file = v
+                .get::<String>()
+                .ok_or("Expected benchmarks.file to be a string.")?
+                .clone();
+        }
+
+        if let Some(v) = json.get("timings") {
+            config.timings = v
+                .get::<String>()
+                .ok_or("Expected benchmarks.timings to be a string.")?
+                .clone();
+        }
+
+        if let Some(v) = json.get("title") {
+            config.title = v
+                .get::<String>()
+                .ok_or("Expected benchmarks.title to be a string.")?
+                .clone();
+        }
+
+        if let Some(v) = json.get("columns") {
+            config.columns = v
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected benchmarks.columns to be an array.")?
+                .iter()
+                .map(|column| {
+                    column
+                        .get::<String>()
+                        .ok_or_else(|| "Expected column to be a string.".to_string())
+                        .and_then(|column| Column::from_str(column))
+                })
+                .collect::<Result<_, _>>()?;
+        }
+
+        if let Some(v) = json.get("sort") {
+            config.sort = v
+                .get::<String>()
+                .ok_or_else(|| "Expected benchmarks.sort to be a string.".to_string())
+                .and_then(|s| SortOrder::from_str(s))?;
+        }
+
+        if let Some(v) = json.get("highlight_slowest") {
+            config.highlight_slowest = *v
+                .get::<bool>()
+                .ok_or("Expected benchmarks.highlight_slowest to be a boolean.")?;
+        }
+
+        if let Some(v) = json.get("bar_style") {
+            config.bar_style = v
+                .get::<String>()
+                .ok_or_else(|| "Expected benchmarks.bar_style to be a string.".to_string())
+                .and_then(|s| BarStyle::from_str(s))?;
+        }
+
+        if let Some(v) = json.get("bar_width") {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let width = v
+                .get::<f64>()
+                .filter(|width| width.fract() == 0.0 && (1.0..=100.0).contains(*width))
+                .map(|width| *width as usize)
+                .ok_or("Expected benchmarks.bar_width to be a number between 1 and 100.")?;
+            config.bar_width = width;
+        }
+
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BarStyle, Column, ReadmeConfig, SortOrder, TableConfig};
+
+    #[test]
+    fn handles_empty_config() {
+        let config = ReadmeConfig::try_from("{}".to_string()).unwrap();
+        assert_eq!(config, ReadmeConfig::default());
+    }
+
+    #[test]
+    fn handles_table_config() {
+        let json = r#"{ "benchmarks": { "columns": ["part_1", "part_2_median", "part_1_parse", "part_1_samples", "part_2_peak_memory", "share", "bar"], "sort": "slowest", "highlight_slowest": true, "bar_style": "ascii", "bar_width": 20 } }"#;
+        let config = ReadmeConfig::try_from(json.to_string()).unwrap();
+        assert_eq!(
+            config.benchmarks,
+            vec![TableConfig {
+                name: None,
+                file: "README.md".into(),
+                timings: "./data/{year}/timings.json".into(),
+                title: "Benchmarks".into(),
+                columns: vec![
+                    Column::Part(1),
+                    Column::PartMedian(2),
+                    Column::PartParse(1),
+                    Column::PartSamples(1),
+                    Column::PartPeakMemory(2),
+                    Column::Share,
+                    Column::Bar
+                ],
+                sort: SortOrder::Slowest,
+                highlight_slowest: true,
+                bar_style: BarStyle::Ascii,
+                bar_width: 20,
+            }]
+        );
+    }
+
+    #[test]
+    fn handles_multiple_tables() {
+        let json = r#"{ "benchmarks": [{}, { "name": "dhat", "file": "docs/BENCHMARKS.md", "timings": "data/timings-dhat.json", "title": "Heap" }] }"#;
+        let config = ReadmeConfig::try_from(json.to_string()).unwrap();
+        assert_eq!(config.benchmarks.len(), 2);
+        assert_eq!(config.benchmarks[0], TableConfig::default());
+        assert_eq!(config.benchmarks[1].name, Some("dhat".into()));
+        assert_eq!(config.benchmarks[1].file, "docs/BENCHMARKS.md");
+        assert_eq!(config.benchmarks[1].timings, "data/timings-dhat.json");
+        assert_eq!(config.benchmarks[1].title, "Heap");
+    }
+
+    #[test]
+    fn rejects_bar_width_out_of_bounds() {
+        for width in ["0", "101", "-5", "2.5"] {
+            let json = format!(r#"{{ "benchmarks": {{ "bar_width": {width} }} }}"#);
+            assert!(ReadmeConfig::try_from(json).is_err(), "{width}");
+        }
+
+        let json = r#"{ "benchmarks": { "bar_width": 100 } }"#;
+        let config = ReadmeConfig::try_from(json.to_string()).unwrap();
+        assert_eq!(config.benchmarks[0].bar_width, 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unknown_columns() {
+        let json = r#"{ "benchmarks": { "columns": ["part_3"] } }"#;
+        ReadmeConfig::try_from(json.to_string()).unwrap();
+    }
+}
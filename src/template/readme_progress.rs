@@ -0,0 +1,233 @@
+/// Module that updates the readme with the progress of every scaffolded day.
+/// Unlike the `advent-readme-stars` action, it only uses local data and needs no session.
+use std::{fs, path::Path};
+
+use crate::template::answers::Answers;
+use crate::template::readme_benchmarks::{locate_year_table, year_marker, Error};
+use crate::template::timings::Timings;
+use crate::template::{Day, Event, Puzzle};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Returns `true` if a part has produced an answer, either stored or while being benchmarked.
+fn is_part_solved(day: Day, part: u8, answers: &Answers, timings: &Timings) -> bool {
+    answers.get(day, part).is_some()
+        || timings
+            .data
+            .iter()
+            .any(|t| t.day == day && t.part(part).is_some())
+}
+
+fn construct_table(
+    prefix: &str,
+    year: u16,
+    event: &Event,
+    days: &[Day],
+    answers: &Answers,
+    timings: &Timings,
+) -> String {
+    let marker = year_marker(MARKER, year);
+    let mut lines: Vec<String> = vec![
+        marker.clone(),
+        format!("{prefix} Progress"),
+        String::new(),
+        "| Day | Part 1 | Part 2 | Answer stored | Benchmarked |".into(),
+        "| :---: | :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    let mut stars = 0;
+
+    for day in days {
+        let parts = event.parts(*day);
+        let solved: Vec<u8> = (1..=parts)
+            .filter(|part| is_part_solved(*day, *part, answers, timings))
+            .collect();
+
+        stars += solved.len();
+
+        // parts that do not exist, e.g. the second part of the last day, are rendered as `-`.
+        let star = |part: u8| match part {
+            part if part > parts => "-",
+            part if solved.contains(&part) => "⭐",
+            _ => "",
+        };
+
+        let answer_stored = if solved.is_empty() {
+            "-"
+        } else if solved.iter().all(|part| answers.get(*day, *part).is_some()) {
+            "✔"
+        } else {
+            "✖"
+        };
+
+        let benchmarked = if timings
+            .data
+            .iter()
+            .any(|t| t.day == *day && (t.part_1.is_some() || t.part_2.is_some()))
+        {
+            "✔"
+        } else {
+            "-"
+        };
+
+        lines.push(format!(
+            "| [Day {}]({}) | {} | {} | {} | {} |",
+            day.into_inner(),
+            Puzzle::new(year, *day).bin_path(),
+            star(1),
+            star(2),
+            answer_stored,
+            benchmarked
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Stars: {stars}**"));
+    lines.push(marker);
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    year: u16,
+    event: &Event,
+    days: &[Day],
+    answers: &Answers,
+    timings: &Timings,
+) -> Result<(), Error> {
+    let positions = locate_year_table(s, &year_marker(MARKER, year), MARKER)?;
+    let table = construct_table("##", year, event, days, answers, timings);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Renders the progress of every scaffolded day of a year.
+pub fn update(year: u16) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    let event = Event::read(year);
+    let days: Vec<Day> = event
+        .days()
+        .filter(|day| Path::new(&Puzzle::new(year, *day).bin_path()).exists())
+        .collect();
+
+    update_content(
+        &mut readme,
+        year,
+        &event,
+        &days,
+        &Answers::read_from_file(year),
+        &Timings::read_from_file(year),
+    )?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::update_content;
+    use crate::{
+        day,
+        template::{
+            answers::{Answer, Answers},
+            timings::{test_helpers::part, PartStatus, Status, Timing, Timings},
+            Event,
+        },
+    };
+
+    static MARKER: &str = "<!--- advent_readme_stars table:2025 --->";
+
+    #[test]
+    fn updates_progress() {
+        let answers = Answers {
+            data: vec![Answer {
+                day: day!(1),
+                part_1: Some("42".into()),
+                part_2: Some("1337".into()),
+            }],
+        };
+
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(2),
+                status: Status::Solved,
+                part_statuses: [PartStatus::Solved; 2],
+                part_1: Some(part(1000_f64)),
+                part_2: None,
+                total_nanos: 1000_f64,
+            }],
+        };
+
+        let mut s = format!("# readme\n{MARKER}\n\n---");
+        let event = Event {
+            days: 3,
+            ..Event::default_for(2025)
+        };
+        let days = [day!(1), day!(2), day!(3)];
+        update_content(&mut s, 2025, &event, &days, &answers, &timings).unwrap();
+        update_content(&mut s, 2025, &event, &days, &answers, &timings).unwrap();
+
+        let expected = [
+            "# readme",
+            "<!--- advent_readme_stars table:2025 --->",
+            "## Progress",
+            "",
+            "| Day | Part 1 | Part 2 | Answer stored | Benchmarked |",
+            "| :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2025_01.rs) | ⭐ | ⭐ | ✔ | - |",
+            "| [Day 2](./src/bin/2025_02.rs) | ⭐ |  | ✖ | ✔ |",
+            "| [Day 3](./src/bin/2025_03.rs) |  | - | - | - |",
+            "",
+            "**Stars: 3**",
+            "<!--- advent_readme_stars table:2025 --->",
+            "",
+            "---",
+        ]
+        .join("\n");
+
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn updates_progress_of_several_years() {
+        let mut s = format!("<!--- advent_readme_stars table:2024 --->\n{MARKER}");
+        let answers = Answers {
+            data: vec![Answer {
+                day: day!(1),
+                part_1: Some("42".into()),
+                part_2: None,
+            }],
+        };
+
+        for year in [2024, 2025] {
+            let event = Event::default_for(year);
+            let days: Vec<_> = event.days().take(2).collect();
+            update_content(&mut s, year, &event, &days, &answers, &Timings::default()).unwrap();
+        }
+
+        let (progress_2024, progress_2025) = s.split_once(MARKER).unwrap();
+        assert!(progress_2024.contains("[Day 2](./src/bin/2024_02.rs)"));
+        assert!(!progress_2024.contains("2025_"));
+        assert!(progress_2025.contains("[Day 2](./src/bin/2025_02.rs)"));
+        assert_eq!(s.matches("**Stars: 1**").count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(
+            &mut s,
+            2025,
+            &Event::default_for(2025),
+            &[],
+            &Answers::default(),
+            &Timings::default(),
+        )
+        .unwrap();
+    }
+}
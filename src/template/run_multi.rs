@@ -1,48 +1,434 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    path::Path,
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Event, Puzzle, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
-    all_days,
-    timings::{Timing, Timings},
+    answers::MISMATCH_MARKER,
+    runner::{FAILED_MARKER, NOT_IMPLEMENTED_MARKER},
+    timings::{MemoryUsage, PartStatus, Status, Timing, Timings},
+    AllDays,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Controls how solution binaries are invoked by [`run_multi`].
+#[derive(Clone, Copy, Debug)]
+pub struct RunOptions {
+    pub is_release: bool,
+    /// Mirrors `--time` to the binaries and collects their timings.
+    pub is_timed: bool,
+    /// Mirrors `--verify` to the binaries and collects answer mismatches.
+    pub is_verify: bool,
+    /// Builds the binaries with the `dhat` profile and collects their heap usage.
+    pub is_memory: bool,
+    /// Number of days that run concurrently. Timed runs are always sequential.
+    pub jobs: usize,
+    /// Kill a binary if it runs longer than this.
+    pub timeout: Option<Duration>,
+    /// Skip the summary and the report of failures, e.g. to print them with [`MultiRun::print_report`]
+    /// once the failures of several runs are merged.
+    pub is_quiet: bool,
+}
 
-    let mut need_space = false;
+impl RunOptions {
+    /// Name of the cargo profile the binaries are built with, e.g. `release`.
+    pub fn profile(&self) -> &'static str {
+        if self.is_memory {
+            "dhat"
+        } else if self.is_release {
+            "release"
+        } else {
+            "dev"
+        }
+    }
+}
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            is_release: false,
+            is_timed: false,
+            is_verify: false,
+            is_memory: false,
+            jobs: 1,
+            timeout: None,
+            is_quiet: false,
+        }
+    }
+}
+
+/// The outcome of running a set of days.
+pub struct MultiRun {
+    /// Parsed timings, if the days were run with `--time`.
+    pub timings: Option<Timings>,
+    /// Parts whose result did not match the answer store, if the days were run with `--verify`.
+    pub mismatches: Vec<(Day, u8)>,
+    /// Days whose binary could not be built, panicked or timed out.
+    pub failures: Vec<(Day, Status)>,
+    /// Heap usage per part, if the days were run with `is_memory`.
+    pub memory: Vec<(Day, u8, MemoryUsage)>,
+    /// Parts that returned an error.
+    pub failed_parts: Vec<(Day, u8)>,
+    /// Parts that are not implemented yet.
+    pub unimplemented_parts: Vec<(Day, u8)>,
+    is_verify: bool,
+}
+
+impl MultiRun {
+    /// Returns `true` if any day or part failed or did not match its stored answers.
+    pub fn has_errors(&self) -> bool {
+        !self.mismatches.is_empty() || !self.failures.is_empty() || !self.failed_parts.is_empty()
+    }
+
+    /// Adds the failures and heap usage of another run of the same days.
+    /// Days and parts that already failed in this run are not added again.
+    pub fn merge(&mut self, other: MultiRun) {
+        for failure in other.failures {
+            if !self.failures.iter().any(|(day, _)| *day == failure.0) {
+                self.failures.push(failure);
             }
-            need_space = true;
+        }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        for (parts, other_parts) in [
+            (&mut self.mismatches, other.mismatches),
+            (&mut self.failed_parts, other.failed_parts),
+            (&mut self.unimplemented_parts, other.unimplemented_parts),
+        ] {
+            for part in other_parts {
+                if !parts.contains(&part) {
+                    parts.push(part);
+                }
+            }
+            parts.sort_unstable();
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        self.failures.sort_unstable_by_key(|(day, _)| *day);
+        self.memory.extend(other.memory);
+        self.is_verify |= other.is_verify;
+    }
 
-            if output.is_empty() {
-                println!("Not solved.");
+    /// Prints mismatched answers, failed and unimplemented parts and failed days.
+    pub fn print_report(&self) {
+        if self.is_verify {
+            if self.mismatches.is_empty() {
+                println!("\nAll stored answers match.");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                let parts: Vec<String> = self
+                    .mismatches
+                    .iter()
+                    .map(|(day, part)| format!("{day}/{part}"))
+                    .collect();
+                println!(
+                    "\n{ANSI_BOLD}{} answer(s) do not match:{ANSI_RESET} {}",
+                    self.mismatches.len(),
+                    parts.join(", ")
+                );
             }
-        });
+        }
+
+        print_parts("failed", &self.failed_parts);
+        print_parts("not implemented", &self.unimplemented_parts);
+
+        if !self.failures.is_empty() {
+            let days: Vec<String> = self
+                .failures
+                .iter()
+                .map(|(day, status)| format!("{day} ({status})"))
+                .collect();
+            println!(
+                "\n{ANSI_BOLD}{} day(s) failed:{ANSI_RESET} {}",
+                self.failures.len(),
+                days.join(" ")
+            );
+        }
+    }
+}
+
+/// The output of a single solution binary, along with the wall time it took to run it.
+pub struct DayRun {
+    pub day: Day,
+    pub status: Status,
+    pub output: Vec<String>,
+    pub elapsed: Duration,
+}
+
+impl DayRun {
+    /// A day whose binary was not run, e.g. because it failed to build.
+    pub fn skipped(day: Day, status: Status, output: Vec<String>) -> Self {
+        Self {
+            day,
+            status,
+            output,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
 
-    if is_timed {
-        let timings = Timings { data: timings };
+/// Run the solutions of a set of days of a year and print a summary.
+pub fn run_multi(year: u16, days_to_run: &HashSet<Day>, options: RunOptions) -> MultiRun {
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = AllDays::new()
+        .filter(|day| days_to_run.contains(day))
+        .collect();
+
+    let event = Event::read(year);
+    let timer = Instant::now();
+
+    let runs = if options.jobs > 1 && !options.is_timed {
+        run_parallel(year, &days, options)
+    } else {
+        run_sequential(year, &days, options)
+    };
+
+    let mismatches: Vec<(Day, u8)> = runs
+        .iter()
+        .flat_map(|run| {
+            [1, 2]
+                .into_iter()
+                .filter(|part| child_commands::is_mismatch(&run.output, *part))
+                .map(|part| (run.day, part))
+        })
+        .collect();
+
+    let memory: Vec<(Day, u8, MemoryUsage)> = runs
+        .iter()
+        .flat_map(|run| {
+            [1, 2].into_iter().filter_map(|part| {
+                child_commands::parse_memory(&run.output, part).map(|usage| (run.day, part, usage))
+            })
+        })
+        .collect();
+
+    // parts a day does not have, e.g. the second part of the last day, are not reported.
+    let parts_with_status = |status: PartStatus| -> Vec<(Day, u8)> {
+        runs.iter()
+            .flat_map(|run| {
+                (1..=event.parts(run.day))
+                    .filter(|part| {
+                        child_commands::parse_part_status(&run.output, *part) == Some(status)
+                    })
+                    .map(|part| (run.day, part))
+            })
+            .collect()
+    };
+
+    let failed_parts = parts_with_status(PartStatus::Failed);
+    let unimplemented_parts = parts_with_status(PartStatus::NotImplemented);
+
+    let failures: Vec<(Day, Status)> = runs
+        .iter()
+        .filter(|run| run.status.is_failure())
+        .map(|run| (run.day, run.status))
+        .collect();
+
+    let timings = if options.is_timed {
+        let timings = Timings {
+            data: runs
+                .iter()
+                .filter(|run| run.status != Status::NotSolved)
+                .map(|run| {
+                    let mut timing = child_commands::parse_exec_time(&run.output, run.day);
+                    timing.status = run.status;
+                    timing
+                })
+                .collect(),
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
         Some(timings)
     } else {
+        if !options.is_quiet {
+            print_summary(&runs, &event, timer.elapsed());
+        }
         None
+    };
+
+    let run = MultiRun {
+        timings,
+        mismatches,
+        failures,
+        memory,
+        failed_parts,
+        unimplemented_parts,
+        is_verify: options.is_verify,
+    };
+
+    if !options.is_quiet {
+        run.print_report();
+    }
+
+    run
+}
+
+fn print_parts(label: &str, parts: &[(Day, u8)]) {
+    if parts.is_empty() {
+        return;
+    }
+
+    let parts: Vec<String> = parts
+        .iter()
+        .map(|(day, part)| format!("{day}/{part}"))
+        .collect();
+
+    println!(
+        "\n{ANSI_BOLD}{} part(s) {label}:{ANSI_RESET} {}",
+        parts.len(),
+        parts.join(", ")
+    );
+}
+
+/// Run days one after another, streaming their output as it is produced.
+fn run_sequential(year: u16, days: &[Day], options: RunOptions) -> Vec<DayRun> {
+    let mut runs = Vec::with_capacity(days.len());
+
+    for (i, &day) in days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        print_day_header(day);
+
+        let run = child_commands::run_solution(Puzzle::new(year, day), options, true);
+        print_status(&run, options.timeout);
+        runs.push(run);
+    }
+
+    runs
+}
+
+/// Run days on a pool of `jobs` worker threads.
+/// The output of each day is buffered and printed in day order once all preceding days have finished.
+fn run_parallel(year: u16, days: &[Day], options: RunOptions) -> Vec<DayRun> {
+    // build all binaries upfront, otherwise the workers would contend for cargo's build lock.
+    // NOTE: bins that fail to compile are reported per day by the workers.
+    let build = match child_commands::build_solutions(options) {
+        Ok(build) => build,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {e:?}");
+            return days
+                .iter()
+                .map(|day| {
+                    let status = if Path::new(&Puzzle::new(year, *day).bin_path()).exists() {
+                        Status::BuildFailed
+                    } else {
+                        Status::NotSolved
+                    };
+                    DayRun::skipped(*day, status, vec![])
+                })
+                .collect();
+        }
+    };
+
+    let queue = Mutex::new(days.iter().copied().enumerate());
+    let (tx, rx) = mpsc::channel();
+
+    let mut runs = Vec::with_capacity(days.len());
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let tx = tx.clone();
+            let queue = &queue;
+            let build = &build;
+
+            scope.spawn(move || loop {
+                let Some((index, day)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+
+                let run = child_commands::run_prebuilt(Puzzle::new(year, day), options, build);
+
+                if tx.send((index, run)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending: BTreeMap<usize, DayRun> = BTreeMap::new();
+
+        for (index, run) in rx {
+            pending.insert(index, run);
+
+            while let Some(run) = pending.remove(&runs.len()) {
+                if !runs.is_empty() {
+                    println!();
+                }
+
+                print_day_header(run.day);
+                run.output.iter().for_each(|line| println!("{line}"));
+                print_status(&run, options.timeout);
+
+                runs.push(run);
+            }
+        }
+    });
+
+    runs
+}
+
+fn print_day_header(day: Day) {
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Prints the status of a day, unless it ran to completion.
+fn print_status(run: &DayRun, timeout: Option<Duration>) {
+    match (run.status, timeout) {
+        (Status::Solved, _) => {}
+        (Status::TimedOut, Some(timeout)) => println!("Timed out after {timeout:.0?}."),
+        (status, _) => println!("{status}"),
+    }
+}
+
+fn print_summary(runs: &[DayRun], event: &Event, total: Duration) {
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+    println!("| Day | Part 1 | Part 2 | Time      | Status        |");
+    println!("| :-: | :----: | :----: | --------: | :------------ |");
+
+    for run in runs {
+        let (part_1, part_2, time) = match run.status {
+            Status::NotSolved | Status::BuildFailed => ("-", "-", "-".to_string()),
+            _ => (
+                format_part_status(&run.output, 1),
+                if event.parts(run.day) > 1 {
+                    format_part_status(&run.output, 2)
+                } else {
+                    "-"
+                },
+                format!("{:.2?}", run.elapsed),
+            ),
+        };
+
+        println!(
+            "| {}  | {part_1:^6} | {part_2:^6} | {time:>9} | {:<13} |",
+            run.day,
+            run.status.to_string()
+        );
+    }
+
+    println!("\n{ANSI_BOLD}Total (Wall):{ANSI_RESET} {ANSI_ITALIC}{total:.2?}{ANSI_RESET}");
+}
+
+/// Reads the status of a part from the output of a solution binary.
+fn format_part_status(output: &[String], part: u8) -> &'static str {
+    if child_commands::is_mismatch(output, part) {
+        return "≠";
+    }
+
+    match child_commands::parse_part_status(output, part) {
+        Some(PartStatus::Solved) => "✔",
+        Some(PartStatus::Failed) => FAILED_MARKER,
+        Some(PartStatus::NotImplemented) => "todo",
+        None => "-",
     }
 }
 
@@ -59,78 +445,365 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{
+        DayRun, Error, RunOptions, FAILED_MARKER, MISMATCH_MARKER, NOT_IMPLEMENTED_MARKER,
+    };
+    use crate::template::{
+        timings::{parse_duration_nanos, MemoryUsage, PartStatus, PartTiming, Status},
+        Day, Puzzle,
+    };
     use std::{
-        io::{BufRead, BufReader},
+        collections::HashMap,
+        io::{BufRead, BufReader, Read},
         path::Path,
         process::{Command, Stdio},
-        thread,
+        str::FromStr,
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Executables and diagnostics of the solution bins, built upfront by [`build_solutions`].
+    #[derive(Debug, Default)]
+    pub struct Prebuild {
+        /// Executable of every bin that compiled, keyed by bin name.
+        executables: HashMap<String, String>,
+        /// Rendered errors of every bin that failed to compile, keyed by bin name.
+        diagnostics: HashMap<String, Vec<String>>,
+        /// Output of cargo, for bins that failed without an error of their own.
+        stderr: Vec<String>,
+    }
+
+    /// Build all solution bins. Bins that fail to compile do not keep the others from being built.
+    pub fn build_solutions(options: RunOptions) -> Result<Prebuild, Error> {
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bins",
+            "--keep-going",
+            "--message-format=json",
+        ];
+        args.extend(profile_args(options));
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()?;
+
+        let mut build = Prebuild {
+            stderr: String::from_utf8_lossy(&output.stderr)
+                .lines()
+                .map(String::from)
+                .collect(),
+            ..Prebuild::default()
+        };
+
+        for message in String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_build_message)
+        {
+            match message {
+                BuildMessage::Executable { bin_name, path } => {
+                    build.executables.insert(bin_name, path);
+                }
+                BuildMessage::Error { bin_name, rendered } => {
+                    build
+                        .diagnostics
+                        .entry(bin_name)
+                        .or_default()
+                        .extend(rendered.lines().map(String::from));
+                }
+            }
+        }
+
+        Ok(build)
+    }
+
+    /// Run the solution bin for a given puzzle.
+    /// When `stream` is set, output is forwarded to stdout/stderr while it is produced.
+    /// Otherwise, stderr is appended to the returned lines.
+    pub fn run_solution(puzzle: Puzzle, options: RunOptions, stream: bool) -> DayRun {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return DayRun::skipped(puzzle.day, Status::NotSolved, vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        match build_solution(puzzle, options, stream) {
+            Ok((Some(executable), _)) => run_executable(puzzle.day, &executable, options, stream),
+            Ok((None, diagnostics)) => {
+                DayRun::skipped(puzzle.day, Status::BuildFailed, diagnostics)
+            }
+            Err(e) => failed_to_run(puzzle.day, Status::BuildFailed, &e, stream),
+        }
+    }
 
-        if is_release {
-            args.push("--release");
+    /// Run the executable of a solution bin that was built by [`build_solutions`].
+    /// Output is not streamed, stderr is appended to the returned lines.
+    pub fn run_prebuilt(puzzle: Puzzle, options: RunOptions, build: &Prebuild) -> DayRun {
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return DayRun::skipped(puzzle.day, Status::NotSolved, vec![]);
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
+        let bin_name = puzzle.to_string();
+
+        match build.executables.get(&bin_name) {
+            Some(executable) => run_executable(puzzle.day, executable, options, false),
+            None => {
+                let diagnostics = build
+                    .diagnostics
+                    .get(&bin_name)
+                    .unwrap_or(&build.stderr)
+                    .clone();
+                DayRun::skipped(puzzle.day, Status::BuildFailed, diagnostics)
+            }
+        }
+    }
+
+    fn failed_to_run(day: Day, status: Status, e: &Error, stream: bool) -> DayRun {
+        let line = format!("Failed to run solution: {e:?}");
+        if stream {
+            eprintln!("{line}");
+        }
+        DayRun::skipped(day, status, vec![line])
+    }
+
+    /// Run an executable, timing only the child process.
+    fn run_executable(day: Day, executable: &str, options: RunOptions, stream: bool) -> DayRun {
+        let timer = Instant::now();
+
+        match spawn_executable(executable, options, stream) {
+            Ok((status, output)) => DayRun {
+                day,
+                status,
+                output,
+                elapsed: timer.elapsed(),
+            },
+            Err((status, e)) => failed_to_run(day, status, &e, stream),
+        }
+    }
+
+    fn spawn_executable(
+        executable: &str,
+        options: RunOptions,
+        stream: bool,
+    ) -> Result<(Status, Vec<String>), (Status, Error)> {
+        let mut args = vec![];
+
+        // mirror `--time` and `--verify` flags to child invocations.
+        if options.is_timed {
             args.push("--time");
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        if options.is_verify {
+            args.push("--verify");
+        }
 
-        let mut cmd = Command::new("cargo")
+        // spawn the binary directly rather than through `cargo run`, so a timeout kills the solution itself.
+        // forward output to stdout/stderr while grabbing stdout lines.
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .spawn()?;
+            .spawn()
+            .map_err(|e| (Status::Panicked, e.into()))?;
+
+        let stdout = cmd
+            .stdout
+            .take()
+            .ok_or((Status::Panicked, Error::BrokenPipe))?;
+        let stderr = cmd
+            .stderr
+            .take()
+            .ok_or((Status::Panicked, Error::BrokenPipe))?;
+
+        let stdout = read_lines(stdout, stream.then_some(print_stdout), true);
+        let stderr = read_lines(stderr, stream.then_some(print_stderr), !stream);
+
+        let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+
+        let status = loop {
+            match cmd.try_wait().map_err(|e| (Status::Panicked, e.into()))? {
+                Some(exit_status) if exit_status.success() => break Status::Solved,
+                Some(_) => break Status::Panicked,
+                None if deadline.is_some_and(|deadline| Instant::now() >= deadline) => {
+                    let _ = cmd.kill();
+                    let _ = cmd.wait();
+                    break Status::TimedOut;
+                }
+                None => thread::sleep(Duration::from_millis(10)),
+            }
+        };
+
+        let mut output = stdout.join().unwrap();
+        output.extend(stderr.join().unwrap());
+
+        Ok((status, output))
+    }
+
+    /// Build the solution bin for a given puzzle and return the path to its executable.
+    /// If compilation failed, no path is returned. Diagnostics are returned when `stream` is not set.
+    fn build_solution(
+        puzzle: Puzzle,
+        options: RunOptions,
+        stream: bool,
+    ) -> Result<(Option<String>, Vec<String>), Error> {
+        let bin_name = puzzle.to_string();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            &bin_name,
+            "--message-format=json-render-diagnostics",
+        ];
+        args.extend(profile_args(options));
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(if stream {
+                Stdio::inherit()
+            } else {
+                Stdio::piped()
+            })
+            .output()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let diagnostics = String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .map(String::from)
+            .collect();
 
-        let mut output = vec![];
+        if !output.status.success() {
+            return Ok((None, diagnostics));
+        }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let executable = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_build_message)
+            .find_map(|message| match message {
+                BuildMessage::Executable {
+                    bin_name: name,
+                    path,
+                } if name == bin_name => Some(path),
+                _ => None,
             });
-        });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        Ok((executable, diagnostics))
+    }
+
+    /// Cargo flags that select the build profile and features of the solution bins.
+    fn profile_args(options: RunOptions) -> Vec<&'static str> {
+        let mut args = vec!["--profile", options.profile()];
+
+        if options.is_memory {
+            args.extend(["--features", "dhat-heap"]);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        // solutions count their allocations if this binary was built to do so.
+        if cfg!(feature = "count-allocations") {
+            args.extend(["--features", "count-allocations"]);
+        }
+
+        args
+    }
 
-        Ok(output)
+    /// A message emitted by `cargo build --message-format=json` about a solution bin.
+    #[derive(Debug, PartialEq)]
+    enum BuildMessage {
+        Executable { bin_name: String, path: String },
+        Error { bin_name: String, rendered: String },
+    }
+
+    /// Reads the executable of a `compiler-artifact` or the error of a `compiler-message`.
+    fn parse_build_message(line: &str) -> Option<BuildMessage> {
+        let json = JsonValue::from_str(line).ok()?;
+        let message = json.get::<HashMap<String, JsonValue>>()?;
+
+        let bin_name = message
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?
+            .clone();
+
+        match message.get("reason")?.get::<String>()?.as_str() {
+            "compiler-artifact" => Some(BuildMessage::Executable {
+                bin_name,
+                path: message.get("executable")?.get::<String>()?.clone(),
+            }),
+            "compiler-message" => {
+                let diagnostic = message
+                    .get("message")?
+                    .get::<HashMap<String, JsonValue>>()?;
+                if diagnostic.get("level")?.get::<String>()? != "error" {
+                    return None;
+                }
+                Some(BuildMessage::Error {
+                    bin_name,
+                    rendered: diagnostic.get("rendered")?.get::<String>()?.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn print_stdout(line: &str) {
+        println!("{line}");
+    }
+
+    fn print_stderr(line: &str) {
+        eprintln!("{line}");
+    }
+
+    /// Reads lines from a child pipe on a separate thread, optionally echoing and keeping them.
+    fn read_lines(
+        reader: impl Read + Send + 'static,
+        echo: Option<fn(&str)>,
+        keep: bool,
+    ) -> JoinHandle<Vec<String>> {
+        thread::spawn(move || {
+            let mut lines = vec![];
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if let Some(echo) = echo {
+                    echo(&line);
+                }
+                if keep {
+                    lines.push(line);
+                }
+            }
+            lines
+        })
+    }
+
+    /// Checks whether a solution bin reported that a part does not match its stored answer.
+    pub fn is_mismatch(output: &[String], part: u8) -> bool {
+        let prefix = format!("Part {part} ");
+        output
+            .iter()
+            .any(|line| line.starts_with(&prefix) && line.contains(MISMATCH_MARKER))
+    }
+
+    /// Reads the heap usage a solution bin reported for a part, e.g. `Part 1 heap: 276 bytes in 3 allocations, peak 232 bytes`.
+    pub fn parse_memory(output: &[String], part: u8) -> Option<MemoryUsage> {
+        let prefix = format!("Part {part} heap: ");
+        output
+            .iter()
+            .find_map(|line| line.strip_prefix(&prefix))
+            .and_then(|usage| usage.parse().ok())
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
+            status: Status::Solved,
+            part_statuses: [1, 2]
+                .map(|part| parse_part_status(output, part).unwrap_or(PartStatus::NotImplemented)),
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
@@ -139,54 +812,70 @@ pub mod child_commands {
         output
             .iter()
             .filter_map(|l| {
-                if !l.contains(" samples)") {
+                if !l.contains(" samples") {
                     return None;
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
+                let Some(timing) = parse_time(l) else {
                     eprintln!("Could not parse timings from line: {l}");
                     return None;
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((part, timing))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, mut timing)| {
                 if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
+                    timing.memory = parse_memory(output, 1);
+                    timings.part_1 = Some(timing);
                 } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                    timing.memory = parse_memory(output, 2);
+                    timings.part_2 = Some(timing);
                 }
 
-                timings.total_nanos += nanos;
+                timings.total_nanos += timing.nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Reads how a part finished from the output of a solution binary.
+    /// Returns `None` if the part did not run.
+    pub fn parse_part_status(output: &[String], part: u8) -> Option<PartStatus> {
+        let prefix = format!("Part {part}:");
+        let line = output.iter().find(|line| line.starts_with(&prefix))?;
+
+        let status = if line.starts_with(&format!("{prefix} {FAILED_MARKER}")) {
+            PartStatus::Failed
+        } else if line.contains(NOT_IMPLEMENTED_MARKER) {
+            PartStatus::NotImplemented
+        } else {
+            PartStatus::Solved
+        };
+
+        Some(status)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    fn parse_time(line: &str) -> Option<PartTiming> {
+        let (head, tail) = line.rsplit_once(" samples")?;
+        let (str_timing, str_samples) = head.rsplit('(').next()?.split_once('@')?;
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        // statistics follow the sample count, e.g. `@ 10 samples, min 1.0ms, median 1.2ms, parse 0.2ms)`.
+        let parse_stat = |name: &str| {
+            tail.trim_end_matches(')')
+                .split(',')
+                .find_map(|s| s.trim().strip_prefix(name))
+                .and_then(|s| parse_duration_nanos(s.trim()))
+        };
 
-        Some((str_timing, parsed_timing))
+        Some(PartTiming {
+            nanos: parse_duration_nanos(str_timing.trim())?,
+            samples: str_samples.trim().parse().ok(),
+            min_nanos: parse_stat("min "),
+            median_nanos: parse_stat("median "),
+            parse_nanos: parse_stat("parse "),
+            memory: None,
+        })
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,9 +894,18 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{
+            is_mismatch, parse_build_message, parse_exec_time, parse_memory, parse_part_status,
+            profile_args, BuildMessage,
+        };
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{
+                run_multi::RunOptions,
+                timings::{MemoryUsage, PartStatus},
+            },
+        };
 
         #[test]
         fn parses_execution_times() {
@@ -220,8 +918,10 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.part_1.unwrap().nanos, 74.13_f64);
+            assert_eq!(res.part_1.unwrap().samples, Some(100000));
+            assert_approx_eq!(res.part_2.unwrap().nanos, 74130000_f64);
+            assert_eq!(res.part_2.unwrap().samples, Some(99999));
         }
 
         #[test]
@@ -235,8 +935,31 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.part_1.unwrap().nanos, 2000000000_f64);
+            assert_eq!(res.part_1.unwrap().samples, Some(5));
+            assert_approx_eq!(res.part_2.unwrap().nanos, 100000000_f64);
+            assert_eq!(res.part_2.unwrap().samples, Some(1));
+        }
+
+        #[test]
+        fn parses_statistics() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 42 (2.0ms @ 10 samples, min 1.5ms, median 1.9ms, parse 300.0µs)"
+                        .into(),
+                    "Part 2: 7 (100ms @ 10 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 102000000_f64);
+            assert_eq!(res.part_1.unwrap().samples, Some(10));
+            assert_eq!(res.part_1.unwrap().min_nanos, Some(1500000_f64));
+            assert_eq!(res.part_1.unwrap().median_nanos, Some(1900000_f64));
+            assert_eq!(res.part_2.unwrap().min_nanos, None);
+            assert_eq!(res.part_1.unwrap().parse_nanos, Some(300000_f64));
+            assert_eq!(res.part_2.unwrap().median_nanos, None);
+            assert_eq!(res.part_2.unwrap().parse_nanos, None);
         }
 
         #[test]
@@ -253,5 +976,190 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_part_statuses() {
+            let output = [
+                "Part 1: ✖\rPart 1: ✖ No solution found (1.0ms)".to_string(),
+                "Part 2: not implemented".to_string(),
+            ];
+            assert_eq!(parse_part_status(&output, 1), Some(PartStatus::Failed));
+            assert_eq!(
+                parse_part_status(&output, 2),
+                Some(PartStatus::NotImplemented)
+            );
+            assert_eq!(
+                parse_part_status(&["Part 1: 42 (1.0ms)".to_string()], 1),
+                Some(PartStatus::Solved)
+            );
+            assert_eq!(parse_part_status(&[], 1), None);
+
+            let res = parse_exec_time(&output, day!(1));
+            assert_eq!(
+                res.part_statuses,
+                [PartStatus::Failed, PartStatus::NotImplemented]
+            );
+        }
+
+        #[test]
+        fn detects_answer_mismatches() {
+            let output = [
+                "Part 1: 42 (1.0ms)".to_string(),
+                "Part 1 matches the stored answer.".to_string(),
+                "Part 2: 7 (1.0ms)".to_string(),
+                "Part 2 does not match the stored answer (expected: 8).".to_string(),
+            ];
+            assert_eq!(is_mismatch(&output, 1), false);
+            assert_eq!(is_mismatch(&output, 2), true);
+        }
+
+        #[test]
+        fn parses_memory_usage() {
+            let output = [
+                "Part 1: 42 (1.0ms)".to_string(),
+                "Part 1 heap: 276 bytes in 3 allocations, peak 232 bytes".to_string(),
+                "Part 2: 7 (1.0ms)".to_string(),
+            ];
+            assert_eq!(
+                parse_memory(&output, 1),
+                Some(MemoryUsage {
+                    total_bytes: 276,
+                    peak_bytes: 232,
+                    allocations: 3
+                })
+            );
+            assert_eq!(parse_memory(&output, 2), None);
+        }
+
+        #[test]
+        fn parses_memory_usage_with_timings() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 42 (1.0ms @ 10 samples)".into(),
+                    "Part 1 heap: 276 bytes in 3 allocations, peak 232 bytes".into(),
+                    "Part 2: 7 (1.0ms @ 10 samples)".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap().memory.unwrap().allocations, 3);
+            assert_eq!(res.part_2.unwrap().memory, None);
+        }
+
+        #[test]
+        fn parses_build_messages() {
+            assert_eq!(
+                parse_build_message(
+                    r#"{"reason":"compiler-artifact","target":{"name":"2025_01"},"executable":"/target/release/2025_01"}"#
+                ),
+                Some(BuildMessage::Executable {
+                    bin_name: "2025_01".into(),
+                    path: "/target/release/2025_01".into()
+                })
+            );
+            assert_eq!(
+                parse_build_message(
+                    r#"{"reason":"compiler-message","target":{"name":"2025_02"},"message":{"level":"error","rendered":"error[E0425]: cannot find value"}}"#
+                ),
+                Some(BuildMessage::Error {
+                    bin_name: "2025_02".into(),
+                    rendered: "error[E0425]: cannot find value".into()
+                })
+            );
+            assert_eq!(
+                parse_build_message(
+                    r#"{"reason":"compiler-message","target":{"name":"2025_02"},"message":{"level":"warning","rendered":"warning: unused"}}"#
+                ),
+                None
+            );
+            assert_eq!(
+                parse_build_message(
+                    r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code"},"executable":null}"#
+                ),
+                None
+            );
+            assert_eq!(
+                parse_build_message(r#"{"reason":"build-finished","success":true}"#),
+                None
+            );
+        }
+
+        #[test]
+        fn builds_with_reported_profile() {
+            let release = RunOptions {
+                is_release: true,
+                ..RunOptions::default()
+            };
+            let memory = RunOptions {
+                is_memory: true,
+                ..release
+            };
+
+            assert_eq!(RunOptions::default().profile(), "dev");
+            assert_eq!(release.profile(), "release");
+            assert_eq!(memory.profile(), "dhat");
+            assert_eq!(profile_args(release)[..2], ["--profile", "release"]);
+            assert_eq!(
+                profile_args(memory)[..4],
+                ["--profile", "dhat", "--features", "dhat-heap"]
+            );
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{
+        day,
+        template::{
+            timings::{MemoryUsage, Status},
+            Day,
+        },
+    };
+
+    use super::MultiRun;
+
+    fn get_mock_run(failures: Vec<(Day, Status)>) -> MultiRun {
+        MultiRun {
+            timings: None,
+            mismatches: vec![],
+            failures,
+            memory: vec![],
+            failed_parts: vec![],
+            unimplemented_parts: vec![],
+            is_verify: false,
+        }
+    }
+
+    #[test]
+    fn merges_failures_of_runs() {
+        let mut run = get_mock_run(vec![(day!(3), Status::TimedOut)]);
+        run.failed_parts = vec![(day!(1), 2)];
+
+        let mut memory_run = get_mock_run(vec![
+            (day!(1), Status::Panicked),
+            (day!(3), Status::Panicked),
+        ]);
+        memory_run.failed_parts = vec![(day!(1), 2), (day!(1), 1)];
+        memory_run.memory = vec![(
+            day!(1),
+            1,
+            MemoryUsage {
+                total_bytes: 1,
+                peak_bytes: 1,
+                allocations: 1,
+            },
+        )];
+
+        run.merge(memory_run);
+
+        assert_eq!(
+            run.failures,
+            vec![(day!(1), Status::Panicked), (day!(3), Status::TimedOut)]
+        );
+        assert_eq!(run.failed_parts, vec![(day!(1), 1), (day!(1), 2)]);
+        assert_eq!(run.memory.len(), 1);
+        assert!(run.has_errors());
     }
 }
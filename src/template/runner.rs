@@ -1,61 +1,365 @@
 /// Encapsulates code that interacts with solution functions.
+use std::borrow::Borrow;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Write};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
+use crate::template::answers::{Answers, Verification, MISMATCH_MARKER};
+use crate::template::examples::ExampleManifest;
+use crate::template::params;
+use crate::template::submissions::{self, Submissions, Verdict};
+use crate::template::timings::MemoryUsage;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_client, read_file, read_file_part, Puzzle, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Printed in place of the answer of a part that returned an error.
+pub const FAILED_MARKER: &str = "✖";
+/// Printed in place of the answer of a part that returned `None`.
+pub const NOT_IMPLEMENTED_MARKER: &str = "not implemented";
+
+/// The result of running a solution part, with the answer formatted as a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    /// The part returned an error, e.g. because the input has no solution.
+    Failed(String),
+    /// The part returned `None`.
+    NotImplemented,
+}
+
+impl Outcome {
+    /// Returns the answer, if the part was solved.
+    #[must_use]
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Outcome::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+/// Return types that solution parts can use.
+///  1. `Option<T>`, where `None` marks the part as not implemented.
+///  2. `Result<T, E>`, where the error is printed as the reason the part failed.
+pub trait PartResult {
+    fn outcome(&self) -> Outcome;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn outcome(&self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Solved(answer.to_string()),
+            None => Outcome::NotImplemented,
+        }
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    fn outcome(&self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Solved(answer.to_string()),
+            Err(e) => Outcome::Failed(e.to_string()),
+        }
+    }
+}
+
+pub fn run_part<I: Copy, T: PartResult>(func: impl Fn(I) -> T, input: I, puzzle: Puzzle, part: u8) {
+    params::set(cli_params());
+    run_measured(func, input, puzzle, part, None);
+}
+
+/// Run a solution part on the result of a parse step. The parse step is timed separately from the part.
+/// The part may take anything the result borrows as, e.g. `&[T]` for a `Vec<T>`.
+pub fn run_parsed_part<P: Borrow<Q>, Q: ?Sized, T: PartResult>(
+    parse: impl Fn(&str) -> P,
+    func: impl Fn(&Q) -> T,
+    input: &str,
+    puzzle: Puzzle,
+    part: u8,
+) {
+    params::set(cli_params());
+
+    let parse_time = time_parse(&parse, input);
+    let parsed = parse(input);
+
+    run_measured(func, parsed.borrow(), puzzle, part, Some(parse_time));
+}
+
+fn run_measured<I: Copy, T: PartResult>(
+    func: impl Fn(I) -> T,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    parse_time: Option<Duration>,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (outcome, mut measurement) =
+        run_timed(&func, input, |outcome| print_result(outcome, &part_str, ""));
+    measurement.parse = parse_time;
+
+    print_result(&outcome, &part_str, &format_duration(&measurement));
+
+    if let Some(memory) = measurement.memory {
+        println!("{part_str} heap: {memory}");
+    }
+
+    #[cfg(feature = "profile")]
+    if has_flag("--profile") && outcome.answer().is_some() {
+        crate::template::profiler::profile(&func, input, puzzle, part);
+    }
+
+    // letter-art answers are verified, recorded and submitted as the letters they spell.
+    let letters = outcome.answer().and_then(read_letters);
+
+    if let Some(letters) = &letters {
+        println!("{part_str} reads as: {ANSI_BOLD}{letters}{ANSI_RESET}");
+    }
+
+    let answer = letters.as_deref().or(outcome.answer());
+
+    if has_flag("--verify") {
+        verify_result(answer, puzzle, part);
+    }
+
+    if let Some(answer) = answer {
+        if has_flag("--record") {
+            record_result(answer, puzzle, part);
+        }
+
+        // only answers the server judged correct are kept for later verification.
+        if let Some(Ok(Verdict::Correct)) = submit_result(answer, puzzle, part) {
+            record_result(answer, puzzle, part);
+        }
+    }
+}
+
+/// Reads the letters of a multi-line answer drawn in one of the Advent of Code fonts.
+#[must_use]
+pub fn read_letters(answer: &str) -> Option<String> {
+    if answer.contains('\n') {
+        crate::ocr::parse_str(answer)
+    } else {
+        None
+    }
+}
+
+/// A solution part that returns the outcome of running it on an input.
+pub type PartFn<'a> = &'a dyn Fn(&str) -> Outcome;
+
+/// Run every case of the example manifest in `data/YYYY/examples/DD.json` and compare the results to the expected answers.
+/// Exits with an error code if any answer does not match.
+pub fn run_examples(puzzle: Puzzle, parts: &[(u8, PartFn)]) {
+    let manifest = ExampleManifest::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for case in &manifest.cases {
+        println!("{ANSI_BOLD}Example `{}`{ANSI_RESET}", case.name);
+        let input = case.read_input(puzzle.year);
+        params::set(case.params.clone());
+
+        for (part, func) in parts {
+            if !should_run_part(*part) {
+                continue;
+            }
+
+            let Some(expected) = case.expected(*part) else {
+                continue;
+            };
+
+            checked += 1;
+
+            let outcome = match func(&input) {
+                Outcome::Solved(answer) => Outcome::Solved(read_letters(&answer).unwrap_or(answer)),
+                outcome => outcome,
+            };
+
+            match outcome {
+                Outcome::Solved(answer) if answer == expected => {
+                    println!("Part {part}: {answer} ✔");
+                }
+                outcome => {
+                    failed += 1;
+                    let answer = match outcome {
+                        Outcome::Solved(answer) => answer,
+                        Outcome::Failed(e) => format!("failed with `{e}`"),
+                        Outcome::NotImplemented => NOT_IMPLEMENTED_MARKER.to_string(),
+                    };
+                    println!(
+                        "Part {part}: {answer} ✖ (expected: {ANSI_BOLD}{expected}{ANSI_RESET})"
+                    );
+                }
+            }
+        }
+    }
+
+    println!("{} of {checked} example answers match.", checked - failed);
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Returns `true` if `flag` was passed on the command line.
+#[must_use]
+pub fn has_flag(flag: &str) -> bool {
+    env::args().any(|x| x == flag)
+}
+
+/// Returns the value following `flag` on the command line, if any.
+fn flag_value(flag: &str) -> Option<String> {
+    let mut args = env::args().skip_while(|x| x != flag);
+    args.next()?;
+    args.next()
+}
+
+/// Returns all parameters passed as `--param name=value`.
+fn cli_params() -> Vec<(String, String)> {
+    let args: Vec<String> = env::args().collect();
+
+    args.windows(2)
+        .filter(|pair| pair[0] == "--param")
+        .map(|pair| {
+            params::parse(&pair[1]).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            })
+        })
+        .collect()
+}
+
+/// Returns `false` if a different part was selected with `--part`.
+#[must_use]
+pub fn should_run_part(part: u8) -> bool {
+    flag_value("--part").is_none_or(|selected| selected == part.to_string())
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Reads the input selected on the command line:
+///  1. `--input <path>` reads a file, or stdin if the path is `-`.
+///  2. `--example` reads `data/YYYY/examples/DD.txt`.
+///  3. `--example-part <part>` reads `data/YYYY/examples/DD-<part>.txt`.
+///  4. otherwise, the puzzle input in `data/YYYY/inputs/DD.txt` is read.
+#[must_use]
+pub fn read_input(puzzle: Puzzle) -> String {
+    if let Some(path) = flag_value("--input") {
+        if path == "-" {
+            return io::read_to_string(io::stdin()).expect("could not read input from stdin");
+        }
+        return fs::read_to_string(path).expect("could not open input file");
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(part) = flag_value("--example-part") {
+        let part = part.parse().expect("`--example-part` must be a number");
+        return read_file_part("examples", puzzle, part);
     }
+
+    if has_flag("--example") {
+        return read_file("examples", puzzle);
+    }
+
+    read_file("inputs", puzzle)
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
+///
+/// Parts that fail or are not implemented are never benched.
+fn run_timed<I: Copy, T: PartResult>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl Fn(&Outcome),
+) -> (Outcome, Measurement) {
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        #[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+        crate::template::allocator::reset();
+
+        let result = func(input);
+        (result, heap_usage())
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let outcome = result.outcome();
+    hook(&outcome);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let mut run = if has_flag("--time") && outcome.answer().is_some() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        Measurement {
+            average: base_time,
+            min: base_time,
+            median: base_time,
+            samples: 1,
+            parse: None,
+            memory: None,
+        }
     };
 
-    (result, run.0, run.1)
+    run.memory = memory;
+
+    (outcome, run)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Heap usage of the first run of a part, as recorded by `dhat`.
+#[cfg(feature = "dhat-heap")]
+fn heap_usage() -> Option<MemoryUsage> {
+    let stats = dhat::HeapStats::get();
+    Some(MemoryUsage {
+        total_bytes: stats.total_bytes,
+        peak_bytes: stats.max_bytes as u64,
+        allocations: stats.total_blocks,
+    })
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Heap usage of the first run of a part, as recorded by the counting allocator.
+#[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+fn heap_usage() -> Option<MemoryUsage> {
+    Some(crate::template::allocator::usage())
+}
+
+#[cfg(not(any(feature = "dhat-heap", feature = "count-allocations")))]
+fn heap_usage() -> Option<MemoryUsage> {
+    None
+}
 
+/// Times the parse step of a solution. It is benched like a part if `--time` is set.
+fn time_parse<P>(parse: impl Fn(&str) -> P, input: &str) -> Duration {
+    let timer = Instant::now();
+    black_box(parse(black_box(input)));
+    let base_time = timer.elapsed();
+
+    if has_flag("--time") {
+        bench(parse, input, &base_time).average
+    } else {
+        base_time
+    }
+}
+
+/// Execution time statistics of a solution part.
+struct Measurement {
+    average: Duration,
+    min: Duration,
+    median: Duration,
+    samples: u128,
+    /// Average time of the parse step, if the solution has one.
+    parse: Option<Duration>,
+    memory: Option<MemoryUsage>,
+}
+
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Measurement {
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
@@ -67,11 +371,17 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
+    timers.sort_unstable();
+
+    Measurement {
         #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+        average: Duration::from_nanos(average_duration(&timers) as u64),
+        min: timers[0],
+        median: timers[timers.len() / 2],
+        samples: bench_iterations,
+        parse: None,
+        memory: None,
+    }
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -82,20 +392,31 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(measurement: &Measurement) -> String {
+    let Measurement {
+        average,
+        min,
+        median,
+        samples,
+        parse,
+        ..
+    } = measurement;
+
+    let parse = parse.map_or_else(String::new, |parse| format!(", parse {parse:.1?}"));
+
+    if *samples == 1 {
+        format!(" ({average:.1?}{parse})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({average:.1?} @ {samples} samples, min {min:.1?}, median {median:.1?}{parse})")
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(outcome: &Outcome, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+    match outcome {
+        Outcome::Solved(result) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -114,25 +435,71 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Outcome::Failed(e) => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                print!("{part}: {FAILED_MARKER}");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: {FAILED_MARKER} {e}{duration_str}");
+            }
+        }
+        Outcome::NotImplemented => {
+            if !is_intermediate_result {
+                println!("{part}: {ANSI_ITALIC}{NOT_IMPLEMENTED_MARKER}{ANSI_RESET}");
             }
         }
     }
 }
 
+/// Compare a result against the answer store and print the outcome.
+fn verify_result(answer: Option<&str>, puzzle: Puzzle, part: u8) {
+    let answers = Answers::read_from_file(puzzle.year);
+    let day = puzzle.day;
+
+    // an unsolved part mismatches whenever an answer was stored for it.
+    let verification = match answer {
+        Some(answer) => answers.verify(day, part, answer),
+        None => answers
+            .get(day, part)
+            .map_or(Verification::Missing, |expected| Verification::Mismatch {
+                expected: expected.into(),
+            }),
+    };
+
+    match verification {
+        Verification::Match => {
+            println!("Part {part} {ANSI_ITALIC}matches the stored answer.{ANSI_RESET}");
+        }
+        Verification::Missing => {
+            println!("Part {part} {ANSI_ITALIC}has no stored answer.{ANSI_RESET}");
+        }
+        Verification::Mismatch { expected } => {
+            println!(
+                "Part {part} {MISMATCH_MARKER} (expected: {ANSI_BOLD}{expected}{ANSI_RESET})."
+            );
+        }
+    }
+}
+
+/// Store a result as the expected answer for a part.
+fn record_result(answer: &str, puzzle: Puzzle, part: u8) {
+    let mut answers = Answers::read_from_file(puzzle.year);
+    answers.set(puzzle.day, part, answer);
+
+    match answers.store_file(puzzle.year) {
+        Ok(()) => println!("Recorded answer for part {part}."),
+        Err(e) => eprintln!("Failed to record answer for part {part}: {e}"),
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+///  2. a session cookie is configured or aoc-cli is installed.
+fn submit_result(
+    result: &str,
+    puzzle: Puzzle,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<Verdict, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +522,67 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let mut submissions = match Submissions::read_from_file(puzzle.year) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Not submitting `{result}`: failed to read the submission log {e}");
+            return None;
+        }
+    };
+
+    if let Err(rejection) = submissions.check(puzzle.day, part, result, submissions::now()) {
+        eprintln!("Not submitting `{result}`: {rejection}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let response = aoc_client::submit(puzzle, part, result).map(|message| {
+        let verdict = submissions.add(puzzle.day, part, result, &message, submissions::now());
+
+        if verdict == Verdict::Unknown {
+            println!("{message}");
+        }
+        println!("{verdict}");
+
+        if let Err(e) = submissions.store_file(puzzle.year) {
+            eprintln!("Failed to store submission: {e}");
+        }
+
+        verdict
+    });
+
+    if let Err(e) = &response {
+        eprintln!("Failed to submit result: {e}");
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(response)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::read_letters;
+
+    #[test]
+    fn reads_letters_of_multi_line_answers() {
+        let answer = "\
+.##..###.
+#..#.#..#
+#..#.###.
+####.#..#
+#..#.#..#
+#..#.###.
+";
+
+        assert_eq!(read_letters(answer), Some("AB".into()));
+        assert_eq!(read_letters(&answer.replace('#', "█")), Some("AB".into()));
+    }
+
+    #[test]
+    fn skips_other_answers() {
+        assert_eq!(read_letters("42"), None);
+        assert_eq!(read_letters("####"), None);
+        assert_eq!(read_letters("1\n2\n3"), None);
+    }
 }
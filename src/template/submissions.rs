@@ -0,0 +1,613 @@
+/// Module that logs every submitted answer, so known-wrong answers are never submitted twice.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+use tinyjson::JsonValue;
+
+use crate::template::{year_path, Day, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/{year}/submissions.json";
+
+/// Direction given by the server for an incorrect answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Hint {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Hint::TooHigh => "too_high",
+            Hint::TooLow => "too_low",
+        }
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Hint::TooHigh => "too high",
+            Hint::TooLow => "too low",
+        })
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too_high" => Ok(Hint::TooHigh),
+            "too_low" => Ok(Hint::TooLow),
+            _ => Err(format!("unknown hint `{s}`.")),
+        }
+    }
+}
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// The part was solved before, so the answer was not checked.
+    AlreadySolved,
+    /// The answer was not checked because the previous one was submitted too recently.
+    RateLimited {
+        wait: Option<u64>,
+    },
+    /// The response could not be understood.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the response message of the server.
+    pub fn parse(message: &str) -> Self {
+        let message = message.to_lowercase();
+
+        if message.contains("that's the right answer") {
+            Verdict::Correct
+        } else if message.contains("that's not the right answer") {
+            let hint = if message.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if message.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Verdict::Incorrect { hint }
+        } else if message.contains("you gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: parse_wait(&message),
+            }
+        } else if message.contains("don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Identifier used when serializing the verdict.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect { .. } => "incorrect",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::RateLimited { .. } => "rate_limited",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "{ANSI_GREEN}✔ Correct!{ANSI_RESET}"),
+            Verdict::Incorrect { hint: Some(hint) } => {
+                write!(
+                    f,
+                    "{ANSI_RED}✖ Incorrect, the answer is {hint}.{ANSI_RESET}"
+                )
+            }
+            Verdict::Incorrect { hint: None } => write!(f, "{ANSI_RED}✖ Incorrect.{ANSI_RESET}"),
+            Verdict::AlreadySolved => write!(f, "This part was already solved."),
+            Verdict::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "Rate limited, wait {} before submitting again.",
+                format_wait(*wait)
+            ),
+            Verdict::RateLimited { wait: None } => {
+                write!(f, "Rate limited, wait before submitting again.")
+            }
+            Verdict::Unknown => write!(f, "Could not read the response of the server."),
+        }
+    }
+}
+
+/// Formats a wait time in seconds like the server does, e.g. `1m 20s`.
+fn format_wait(seconds: u64) -> String {
+    match (seconds / 60, seconds % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+/// Reads how long the server asked to wait in seconds, e.g. from `You have 1m 20s left to wait.` or `Please wait 5 minutes`.
+pub fn parse_wait(message: &str) -> Option<u64> {
+    let message = message.to_lowercase();
+
+    let left_to_wait = Regex::new(r"you have ((?:\d+[hms] ?)+) left to wait").unwrap();
+    if let Some(captures) = left_to_wait.captures(&message) {
+        let unit = Regex::new(r"(\d+)([hms])").unwrap();
+        return Some(
+            unit.captures_iter(&captures[1])
+                .map(|c| {
+                    let value: u64 = c[1].parse().unwrap_or_default();
+                    match &c[2] {
+                        "h" => value * 3600,
+                        "m" => value * 60,
+                        _ => value,
+                    }
+                })
+                .sum(),
+        );
+    }
+
+    let wait_minutes = Regex::new(r"wait (one|\d+) minutes?").unwrap();
+    let captures = wait_minutes.captures(&message)?;
+    let minutes = match &captures[1] {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    Some(minutes * 60)
+}
+
+/// Represents a single submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// No answer should be submitted before this time, in seconds since the unix epoch.
+    pub retry_after: Option<u64>,
+}
+
+/// Reason an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadyCorrect {
+        answer: String,
+    },
+    AlreadyIncorrect,
+    /// The answer is outside the bounds set by a previous answer that was too high or too low.
+    OutsideBounds {
+        hint: Hint,
+        bound: String,
+    },
+    Cooldown {
+        remaining: u64,
+    },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadyCorrect { answer } => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Rejection::AlreadyIncorrect => write!(f, "this answer was already judged incorrect."),
+            Rejection::OutsideBounds { hint, bound } => {
+                write!(f, "this answer is {hint}, `{bound}` was already {hint}.")
+            }
+            Rejection::Cooldown { remaining } => write!(
+                f,
+                "the server asked to wait another {} before submitting.",
+                format_wait(*remaining)
+            ),
+        }
+    }
+}
+
+/// Represents every answer that was submitted.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions of a year to a JSON file.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(year_path(SUBMISSIONS_FILE_PATH, year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions of a year from a JSON file. If not present, returns an empty log.
+    /// Fails if the file can not be read or parsed, so a broken log is never overwritten.
+    pub fn read_from_file(year: u16) -> Result<Self, String> {
+        let path = year_path(SUBMISSIONS_FILE_PATH, year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Submissions::try_from(contents).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
+    }
+
+    /// Returns all submissions for a part, oldest first.
+    pub fn for_part(&self, day: Day, part: u8) -> impl Iterator<Item = &Submission> {
+        self.data
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+
+    /// Checks if an answer may be submitted at `now`, in seconds since the unix epoch.
+    pub fn check(&self, day: Day, part: u8, answer: &str, now: u64) -> Result<(), Rejection> {
+        // the cooldown applies to all puzzles.
+        if let Some(retry_after) = self.data.iter().filter_map(|s| s.retry_after).max() {
+            if retry_after > now {
+                return Err(Rejection::Cooldown {
+                    remaining: retry_after - now,
+                });
+            }
+        }
+
+        for submission in self.for_part(day, part) {
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Err(Rejection::AlreadyCorrect {
+                        answer: submission.answer.clone(),
+                    })
+                }
+                Verdict::Incorrect { .. } if submission.answer == answer => {
+                    return Err(Rejection::AlreadyIncorrect)
+                }
+                Verdict::Incorrect { hint: Some(hint) } => {
+                    let (Ok(bound), Ok(value)) =
+                        (submission.answer.parse::<i128>(), answer.parse::<i128>())
+                    else {
+                        continue;
+                    };
+
+                    let is_outside = match hint {
+                        Hint::TooHigh => value >= bound,
+                        Hint::TooLow => value <= bound,
+                    };
+
+                    if is_outside {
+                        return Err(Rejection::OutsideBounds {
+                            hint,
+                            bound: submission.answer.clone(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Logs a submitted answer along with the response message of the server.
+    pub fn add(&mut self, day: Day, part: u8, answer: &str, message: &str, now: u64) -> Verdict {
+        let verdict = Verdict::parse(message);
+
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp: now,
+            retry_after: parse_wait(message).map(|wait| now + wait),
+        });
+
+        verdict
+    }
+}
+
+/// Current time in seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let number = |x: u64| JsonValue::Number(x as f64);
+
+        let (hint, wait) = match value.verdict {
+            Verdict::Incorrect { hint } => (hint, None),
+            Verdict::RateLimited { wait } => (None, wait),
+            _ => (None, None),
+        };
+
+        map.insert(
+            "hint".into(),
+            hint.map_or(JsonValue::Null, |h| JsonValue::String(h.as_str().into())),
+        );
+        map.insert("wait".into(), wait.map_or(JsonValue::Null, number));
+        map.insert("timestamp".into(), number(value.timestamp));
+        map.insert(
+            "retry_after".into(),
+            value.retry_after.map_or(JsonValue::Null, number),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+        };
+
+        let hint = match json.get("hint").and_then(|v| v.get::<String>()) {
+            Some(s) => Some(Hint::from_str(s)?),
+            None => None,
+        };
+
+        let verdict = match json.get("verdict").and_then(|v| v.get::<String>()) {
+            Some(s) if s == "correct" => Verdict::Correct,
+            Some(s) if s == "incorrect" => Verdict::Incorrect { hint },
+            Some(s) if s == "already_solved" => Verdict::AlreadySolved,
+            Some(s) if s == "rate_limited" => Verdict::RateLimited {
+                wait: number("wait"),
+            },
+            Some(s) if s == "unknown" => Verdict::Unknown,
+            _ => return Err("Expected submission.verdict to be a known verdict.".into()),
+        };
+
+        Ok(Submission {
+            day,
+            part,
+            answer,
+            verdict,
+            timestamp: number("timestamp")
+                .ok_or("Expected submission.timestamp to be a number.")?,
+            retry_after: number("retry_after"),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use crate::day;
+
+    use super::{format_wait, parse_wait, Hint, Rejection, Submissions, Verdict};
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer!  You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            Verdict::Incorrect { hint: None }
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait."),
+            Verdict::RateLimited { wait: Some(80) }
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(Verdict::parse("<html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(parse_wait("You have 34s left to wait."), Some(34));
+        assert_eq!(parse_wait("You have 1h 2m 3s left to wait."), Some(3723));
+        assert_eq!(
+            parse_wait("Please wait one minute before trying again."),
+            Some(60)
+        );
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(parse_wait("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn formats_wait_times() {
+        assert_eq!(format_wait(34), "34s");
+        assert_eq!(format_wait(300), "5m");
+        assert_eq!(format_wait(80), "1m 20s");
+        assert_eq!(
+            Verdict::RateLimited { wait: Some(80) }.to_string(),
+            "Rate limited, wait 1m 20s before submitting again."
+        );
+    }
+
+    #[test]
+    fn rejects_known_answers() {
+        let mut submissions = Submissions::default();
+        submissions.add(
+            day!(1),
+            1,
+            "100",
+            "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+            1000,
+        );
+        submissions.add(
+            day!(1),
+            1,
+            "10",
+            "That's not the right answer; your answer is too low.",
+            1100,
+        );
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "50", 1030),
+            Err(Rejection::Cooldown { remaining: 30 })
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "100", 2000),
+            Err(Rejection::AlreadyIncorrect)
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "120", 2000),
+            Err(Rejection::OutsideBounds {
+                hint: Hint::TooHigh,
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "5", 2000),
+            Err(Rejection::OutsideBounds {
+                hint: Hint::TooLow,
+                bound: "10".into()
+            })
+        );
+        assert_eq!(submissions.check(day!(1), 1, "50", 2000), Ok(()));
+        assert_eq!(submissions.check(day!(1), 2, "120", 2000), Ok(()));
+
+        submissions.add(day!(1), 1, "50", "That's the right answer!", 2000);
+        assert_eq!(
+            submissions.check(day!(1), 1, "51", 3000),
+            Err(Rejection::AlreadyCorrect {
+                answer: "50".into()
+            })
+        );
+    }
+
+    #[test]
+    fn handles_json_submissions() {
+        let mut submissions = Submissions::default();
+        submissions.add(
+            day!(2),
+            2,
+            "42",
+            "You gave an answer too recently. You have 30s left to wait.",
+            1000,
+        );
+        submissions.add(
+            day!(2),
+            2,
+            "43",
+            "That's not the right answer; your answer is too low.",
+            1100,
+        );
+
+        let json = JsonValue::from(submissions.clone()).stringify().unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+
+        assert_eq!(parsed.data, submissions.data);
+        assert_eq!(parsed.data[0].retry_after, Some(1030));
+        assert_eq!(
+            parsed.data[1].verdict,
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow)
+            }
+        );
+    }
+}
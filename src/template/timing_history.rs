@@ -0,0 +1,327 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::{year_path, Day};
+
+static HISTORY_FILE_PATH: &str = "./data/{year}/timings-history.json";
+
+/// Represents a stored benchmark of a single day, along with the environment it was taken in.
+#[derive(Clone, Debug)]
+pub struct HistoryRecord {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the git commit, if the repository could be inspected.
+    pub commit: Option<String>,
+    /// Output of `rustc --version`, if available.
+    pub rustc: Option<String>,
+    pub profile: String,
+    pub timing: Timing,
+}
+
+/// The best timing ever recorded for a part.
+#[derive(Clone, Debug)]
+pub struct BestTiming<'a> {
+    pub part: u8,
+    pub nanos: f64,
+    pub record: &'a HistoryRecord,
+}
+
+/// Represents every stored benchmark over time.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct TimingHistory {
+    pub data: Vec<HistoryRecord>,
+}
+
+impl TimingHistory {
+    /// Dehydrate history of a year to a JSON file.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(year_path(HISTORY_FILE_PATH, year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history of a year from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file(year: u16) -> Self {
+        fs::read_to_string(year_path(HISTORY_FILE_PATH, year))
+            .map_err(|x| x.to_string())
+            .and_then(TimingHistory::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Append a record for every timing, stamped with the current time and environment.
+    pub fn append(&mut self, timings: &Timings, profile: &str) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let commit = command_output("git", &["rev-parse", "--short", "HEAD"]);
+        let rustc = command_output("rustc", &["--version"]);
+
+        for timing in &timings.data {
+            self.data.push(HistoryRecord {
+                timestamp,
+                commit: commit.clone(),
+                rustc: rustc.clone(),
+                profile: profile.into(),
+                timing: timing.clone(),
+            });
+        }
+    }
+
+    /// Returns the records of a day, oldest first.
+    pub fn for_day(&self, day: Day) -> Vec<&HistoryRecord> {
+        let mut records: Vec<&HistoryRecord> =
+            self.data.iter().filter(|r| r.timing.day == day).collect();
+        records.sort_by_key(|r| r.timestamp);
+        records
+    }
+
+    /// Returns the fastest recorded timing per part of a day.
+    pub fn best(&self, day: Day) -> Vec<BestTiming<'_>> {
+        [1, 2]
+            .into_iter()
+            .filter_map(|part| {
+                self.for_day(day)
+                    .into_iter()
+                    .filter_map(|record| {
+                        record.timing.part_nanos(part).map(|nanos| BestTiming {
+                            part,
+                            nanos,
+                            record,
+                        })
+                    })
+                    .min_by(|a, b| a.nanos.total_cmp(&b.nanos))
+            })
+            .collect()
+    }
+}
+
+fn command_output(command: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(command).args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let output = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!output.is_empty()).then_some(output)
+}
+
+/// Formats a unix timestamp as a UTC date, e.g. `2025-12-01 06:00`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    #[allow(clippy::cast_possible_wrap)]
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<TimingHistory> for JsonValue {
+    fn from(value: TimingHistory) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for TimingHistory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(TimingHistory {
+            data: json_data
+                .iter()
+                .map(HistoryRecord::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryRecord> for JsonValue {
+    fn from(value: &HistoryRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "rustc".into(),
+            value
+                .rustc
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history record to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected record.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.commit to be null or string.")?;
+
+        let rustc = json
+            .get("rustc")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.rustc to be null or string.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.profile to be a string.")?;
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected record.timing to be present.")
+            .map(Timing::try_from)??;
+
+        Ok(HistoryRecord {
+            timestamp,
+            commit: commit.cloned(),
+            rustc: rustc.cloned(),
+            profile: profile.clone(),
+            timing,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{
+        day,
+        template::timings::{test_helpers::part, PartStatus, Status, Timing},
+    };
+
+    use super::{format_timestamp, HistoryRecord, TimingHistory};
+
+    fn get_mock_record(timestamp: u64, part_1: f64, part_2: Option<f64>) -> HistoryRecord {
+        HistoryRecord {
+            timestamp,
+            commit: Some("abc1234".into()),
+            rustc: None,
+            profile: "release".into(),
+            timing: Timing {
+                day: day!(1),
+                status: Status::Solved,
+                part_statuses: [PartStatus::Solved; 2],
+                part_1: Some(part(part_1)),
+                part_2: part_2.map(part),
+                total_nanos: 0_f64,
+            },
+        }
+    }
+
+    #[test]
+    fn handles_json_history() {
+        let json = r#"{ "data": [{ "timestamp": 1764568800, "commit": "abc1234", "rustc": null, "profile": "release", "timing": { "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 } }] }"#.to_string();
+        let history = TimingHistory::try_from(json).unwrap();
+        assert_eq!(history.data.len(), 1);
+        assert_eq!(history.data[0].commit, Some("abc1234".into()));
+        assert_eq!(history.data[0].rustc, None);
+        assert_eq!(history.data[0].timing.day, day!(1));
+    }
+
+    #[test]
+    fn finds_best_timings() {
+        let history = TimingHistory {
+            data: vec![
+                get_mock_record(3, 2e6, None),
+                get_mock_record(1, 3e6, Some(1e7)),
+                get_mock_record(2, 1e6, Some(2e7)),
+            ],
+        };
+
+        let records = history.for_day(day!(1));
+        assert_eq!(records[0].timestamp, 1);
+        assert_eq!(records[2].timestamp, 3);
+
+        let best = history.best(day!(1));
+        assert_eq!(best.len(), 2);
+        assert_eq!(best[0].part, 1);
+        assert_eq!(best[0].record.timestamp, 2);
+        assert_eq!(best[1].part, 2);
+        assert_eq!(best[1].record.timestamp, 1);
+
+        assert_eq!(history.best(day!(2)).len(), 0);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_764_568_800), "2025-12-01 06:00");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29 12:34");
+    }
+}
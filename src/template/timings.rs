@@ -1,19 +1,244 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{year_path, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+pub static TIMINGS_FILE_PATH: &str = "./data/{year}/timings.json";
+
+/// Represents how the solution binary of a day finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    Panicked,
+    TimedOut,
+    BuildFailed,
+}
+
+impl Status {
+    /// Returns `true` if the solution binary was present but could not produce a result.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Status::Panicked | Status::TimedOut | Status::BuildFailed
+        )
+    }
+
+    /// Identifier used when serializing the status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::NotSolved => "not_solved",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::BuildFailed => "build_failed",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Solved => "Solved.",
+            Status::NotSolved => "Not solved.",
+            Status::Panicked => "Panicked.",
+            Status::TimedOut => "Timed out.",
+            Status::BuildFailed => "Build failed.",
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Status::Solved,
+            Status::NotSolved,
+            Status::Panicked,
+            Status::TimedOut,
+            Status::BuildFailed,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == s)
+        .ok_or_else(|| format!("unknown status `{s}`."))
+    }
+}
+
+/// Represents how a single part of a solution finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// The part returned an error.
+    Failed,
+    /// The part returned `None` or is not part of the solution.
+    NotImplemented,
+}
+
+impl PartStatus {
+    /// Identifier used when serializing the status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Failed => "failed",
+            PartStatus::NotImplemented => "not_implemented",
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PartStatus::Solved => "Solved.",
+            PartStatus::Failed => "Failed.",
+            PartStatus::NotImplemented => "Not implemented.",
+        })
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            PartStatus::Solved,
+            PartStatus::Failed,
+            PartStatus::NotImplemented,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == s)
+        .ok_or_else(|| format!("unknown part status `{s}`."))
+    }
+}
+
+/// Represents the benchmark time of a single part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Average execution time in nanoseconds.
+    pub nanos: f64,
+    /// Number of samples the average was taken over.
+    /// `None` for timings stored before sample counts were tracked.
+    pub samples: Option<u64>,
+    /// Fastest sample in nanoseconds, if known.
+    pub min_nanos: Option<f64>,
+    /// Median sample in nanoseconds, if known.
+    pub median_nanos: Option<f64>,
+    /// Average time of the parse step of the solution in nanoseconds, if it has one.
+    /// The parse step is not included in `nanos`.
+    pub parse_nanos: Option<f64>,
+    /// Heap usage, if the part was measured with `cargo time --memory`.
+    pub memory: Option<MemoryUsage>,
+}
+
+/// Represents the heap usage of a single run of a part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Bytes allocated over the whole run.
+    pub total_bytes: u64,
+    /// Maximum bytes allocated at a single point in time.
+    pub peak_bytes: u64,
+    pub allocations: u64,
+}
+
+impl Display for MemoryUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bytes in {} allocations, peak {} bytes",
+            self.total_bytes, self.allocations, self.peak_bytes
+        )
+    }
+}
+
+impl FromStr for MemoryUsage {
+    type Err = String;
+
+    /// Parses the `Display` output, e.g. `276 bytes in 3 allocations, peak 232 bytes`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = || -> Option<Self> {
+            let (total_bytes, rest) = s.trim().split_once(" bytes in ")?;
+            let (allocations, rest) = rest.split_once(" allocations, peak ")?;
+            let peak_bytes = rest.strip_suffix(" bytes")?;
+
+            Some(MemoryUsage {
+                total_bytes: total_bytes.parse().ok()?,
+                peak_bytes: peak_bytes.parse().ok()?,
+                allocations: allocations.parse().ok()?,
+            })
+        };
+
+        parse().ok_or_else(|| format!("Could not parse memory usage `{s}`."))
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub status: Status,
+    /// How part 1 and part 2 finished.
+    pub part_statuses: [PartStatus; 2],
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Returns the timing of a part, if present.
+    pub fn part(&self, part: u8) -> Option<PartTiming> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+
+    /// Returns how a part finished.
+    pub fn part_status(&self, part: u8) -> PartStatus {
+        match part {
+            1 | 2 => self.part_statuses[usize::from(part - 1)],
+            _ => PartStatus::NotImplemented,
+        }
+    }
+
+    /// Returns a mutable reference to the timing of a part, if present.
+    pub fn part_mut(&mut self, part: u8) -> Option<&mut PartTiming> {
+        match part {
+            1 => self.part_1.as_mut(),
+            2 => self.part_2.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Returns the timing of a part in nanoseconds, if present.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part).map(|timing| timing.nanos)
+    }
+}
+
+/// Represents the change of a part's timing between a stored and a fresh run.
+#[derive(Clone, Debug)]
+pub struct TimingChange {
+    pub day: Day,
+    pub part: u8,
+    pub old_nanos: f64,
+    /// `None` if the fresh run did not produce a timing for the part.
+    pub new_nanos: Option<f64>,
+}
+
+impl TimingChange {
+    /// Relative change in percent. Positive values are slower than before.
+    pub fn percent(&self) -> Option<f64> {
+        self.new_nanos
+            .map(|new_nanos| (new_nanos - self.old_nanos) / self.old_nanos * 100_f64)
+    }
+
+    /// A part regressed if it got slower by more than `threshold` percent or lost its timing.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent().is_none_or(|percent| percent > threshold)
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,16 +247,21 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings of a year to a JSON file.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(year_path(TIMINGS_FILE_PATH, year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings of a year from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: u16) -> Self {
+        Self::read_from_path(&year_path(TIMINGS_FILE_PATH, year))
+    }
+
+    /// Rehydrate timings from the JSON file at `path`. If not present, returns empty timings.
+    pub fn read_from_path(path: &str) -> Self {
+        fs::read_to_string(path)
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +281,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -60,13 +290,81 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Compare per-part timings of `new` against `self`.
+    /// Only parts which have a stored timing are compared.
+    pub fn compare(&self, new: &Self) -> Vec<TimingChange> {
+        let mut changes = vec![];
+
+        for timing in &new.data {
+            let Some(stored) = self.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            for part in [1, 2] {
+                if let Some(old_nanos) = stored.part_nanos(part) {
+                    changes.push(TimingChange {
+                        day: timing.day,
+                        part,
+                        old_nanos,
+                        new_nanos: timing.part_nanos(part),
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Returns `true` if every part of a day has been benchmarked, e.g. only the first part of the last day.
+    pub fn is_day_complete(&self, day: Day, parts: u8) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && (1..=parts).all(|part| t.part(part).is_some()))
+    }
+}
+
+/// Formats nanoseconds like a `Debug`-formatted [`std::time::Duration`], e.g. `74.1ns`.
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = std::time::Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+/// Formats a byte count with binary units, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Parses a `Debug`-formatted [`std::time::Duration`] such as `74.13ns` into nanoseconds.
+pub fn parse_duration_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
     }
 }
 
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -112,25 +410,37 @@ impl From<&Timing> for JsonValue {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        map.insert(
+            "part_statuses".into(),
+            JsonValue::Array(
+                value
+                    .part_statuses
+                    .iter()
+                    .map(|status| JsonValue::String(status.as_str().into()))
+                    .collect(),
+            ),
+        );
 
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_1
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_2
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         JsonValue::Object(map)
@@ -151,25 +461,58 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // NOTE: timings stored before statuses were tracked are always solved.
+        let status = match json.get("status") {
+            None => Status::Solved,
+            Some(v) => v
+                .get::<String>()
+                .and_then(|s| Status::from_str(s).ok())
+                .ok_or("Expected timing.status to be a known status.")?,
+        };
+
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .ok_or("Expected timing.part_1 to be present.")
+            .map(PartTiming::from_json)??;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .ok_or("Expected timing.part_2 to be present.")
+            .map(PartTiming::from_json)??;
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: timings stored before part statuses were tracked only contain solved parts.
+        let part_statuses = match json.get("part_statuses") {
+            None => [part_1, part_2].map(|timing| {
+                if timing.is_some() {
+                    PartStatus::Solved
+                } else {
+                    PartStatus::NotImplemented
+                }
+            }),
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .map(|statuses| {
+                    statuses
+                        .iter()
+                        .filter_map(|s| s.get::<String>())
+                        .filter_map(|s| PartStatus::from_str(s).ok())
+                        .collect::<Vec<_>>()
+                })
+                .and_then(|statuses| <[PartStatus; 2]>::try_from(statuses).ok())
+                .ok_or("Expected timing.part_statuses to be an array of two known statuses.")?,
+        };
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            status,
+            part_statuses,
+            part_1,
+            part_2,
             total_nanos,
         })
     }
@@ -177,30 +520,203 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            value
+                .samples
+                .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+        );
+        map.insert(
+            "min_nanos".into(),
+            value.min_nanos.map_or(JsonValue::Null, JsonValue::Number),
+        );
+        map.insert(
+            "median_nanos".into(),
+            value
+                .median_nanos
+                .map_or(JsonValue::Null, JsonValue::Number),
+        );
+        map.insert(
+            "parse_nanos".into(),
+            value.parse_nanos.map_or(JsonValue::Null, JsonValue::Number),
+        );
+        map.insert(
+            "memory".into(),
+            value
+                .memory
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&MemoryUsage> for JsonValue {
+    fn from(value: &MemoryUsage) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert(
+                "total_bytes".into(),
+                JsonValue::Number(value.total_bytes as f64),
+            );
+            map.insert(
+                "peak_bytes".into(),
+                JsonValue::Number(value.peak_bytes as f64),
+            );
+            map.insert(
+                "allocations".into(),
+                JsonValue::Number(value.allocations as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryUsage {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory usage to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let get = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or_else(|| format!("Expected memory.{key} to be a number."))
+        };
+
+        Ok(MemoryUsage {
+            total_bytes: get("total_bytes")?,
+            peak_bytes: get("peak_bytes")?,
+            allocations: get("allocations")?,
+        })
+    }
+}
+
+impl PartTiming {
+    /// Reads a part timing from JSON.
+    /// Besides objects, this accepts `null` and the `Debug`-formatted strings of the previous schema.
+    fn from_json(value: &JsonValue) -> Result<Option<Self>, String> {
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        if let Some(legacy) = value.get::<String>() {
+            let nanos = parse_duration_nanos(legacy)
+                .ok_or_else(|| format!("Could not parse part timing `{legacy}`."))?;
+            return Ok(Some(PartTiming {
+                nanos,
+                samples: None,
+                min_nanos: None,
+                median_nanos: None,
+                parse_nanos: None,
+                memory: None,
+            }));
+        }
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be null, a string or an object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part timing.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64);
+
+        let min_nanos = json.get("min_nanos").and_then(|v| v.get::<f64>().copied());
+        let median_nanos = json
+            .get("median_nanos")
+            .and_then(|v| v.get::<f64>().copied());
+        let parse_nanos = json
+            .get("parse_nanos")
+            .and_then(|v| v.get::<f64>().copied());
+
+        let memory = match json.get("memory") {
+            Some(v) if !v.is_null() => Some(MemoryUsage::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(Some(PartTiming {
+            nanos,
+            samples,
+            min_nanos,
+            median_nanos,
+            parse_nanos,
+            memory,
+        }))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Helpers for the tests of modules that work with timings.
+#[cfg(feature = "test_lib")]
+pub(crate) mod test_helpers {
+    use super::PartTiming;
+
+    /// A part timing that took `nanos` on average over 10 samples.
+    pub(crate) fn part(nanos: f64) -> PartTiming {
+        PartTiming {
+            nanos,
+            samples: Some(10),
+            min_nanos: None,
+            median_nanos: None,
+            parse_nanos: None,
+            memory: None,
+        }
+    }
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{test_helpers::part, PartStatus, Status, Timing, Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(10_000_000_f64)),
+                    part_2: Some(part(20_000_000_f64)),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(30_000_000_f64)),
+                    part_2: Some(part(40_000_000_f64)),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(40_000_000_f64)),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
@@ -209,20 +725,102 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::timings::{
+                parse_duration_nanos, test_helpers::part, MemoryUsage, PartStatus, PartTiming,
+                Status, Timings,
+            },
+        };
+
+        #[test]
+        fn handles_json_memory_usage() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": { "nanos": 1000, "samples": 10, "memory": { "total_bytes": 276, "peak_bytes": 232, "allocations": 3 } }, "part_2": { "nanos": 1000, "samples": 10, "memory": null }, "total_nanos": 2000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(
+                timings.data[0].part_1.unwrap().memory,
+                Some(MemoryUsage {
+                    total_bytes: 276,
+                    peak_bytes: 232,
+                    allocations: 3
+                })
+            );
+            assert_eq!(timings.data[0].part_2.unwrap().memory, None);
+        }
 
         #[test]
         fn handles_json_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 100, "parse_nanos": 2000 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    samples: Some(100),
+                    parse_nanos: Some(2000_f64),
+                    ..part(1_000_000_f64)
+                })
+            );
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_legacy_json_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    samples: None,
+                    ..part(1_000_000_f64)
+                })
+            );
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn parses_duration_units() {
+            assert_eq!(parse_duration_nanos("74.5ns"), Some(74.5_f64));
+            assert_eq!(parse_duration_nanos("2µs"), Some(2000_f64));
+            assert_eq!(parse_duration_nanos("3ms"), Some(3_000_000_f64));
+            assert_eq!(parse_duration_nanos("1.5s"), Some(1_500_000_000_f64));
+            assert_eq!(parse_duration_nanos("foo"), None);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_legacy_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "foo", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn handles_json_statuses() {
+            let json = r#"{ "data": [{ "day": "01", "status": "timed_out", "part_1": null, "part_2": null, "total_nanos": 0 }, { "day": "02", "part_1": "1ms", "part_2": "1ms", "total_nanos": 2000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].status, Status::TimedOut);
+            assert_eq!(timings.data[1].status, Status::Solved);
+        }
+
+        #[test]
+        fn handles_json_part_statuses() {
+            let json = r#"{ "data": [{ "day": "01", "part_statuses": ["failed", "not_implemented"], "part_1": null, "part_2": null, "total_nanos": 0 }, { "day": "02", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_status(1), PartStatus::Failed);
+            assert_eq!(timings.data[0].part_status(2), PartStatus::NotImplemented);
+            assert_eq!(
+                timings.data[1].part_statuses,
+                [PartStatus::Solved, PartStatus::NotImplemented]
+            );
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,9 +845,32 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::timings::{format_bytes, MemoryUsage};
+        use std::{collections::HashMap, str::FromStr};
         use tinyjson::JsonValue;
 
+        #[test]
+        fn formats_memory_usage() {
+            let memory = MemoryUsage {
+                total_bytes: 276,
+                peak_bytes: 232,
+                allocations: 3,
+            };
+            assert_eq!(
+                memory.to_string(),
+                "276 bytes in 3 allocations, peak 232 bytes"
+            );
+            assert_eq!(MemoryUsage::from_str(&memory.to_string()), Ok(memory));
+            assert!(MemoryUsage::from_str("276 bytes").is_err());
+        }
+
+        #[test]
+        fn formats_bytes() {
+            assert_eq!(format_bytes(232), "232 B");
+            assert_eq!(format_bytes(1536), "1.5 KiB");
+            assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -271,7 +892,7 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{test_helpers::part, PartStatus, Status, Timing, Timings},
         };
 
         #[test]
@@ -279,13 +900,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(1_000_000_f64)),
+                    part_2: Some(part(2_000_000_f64)),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1), 2), true);
         }
 
         #[test]
@@ -293,13 +916,16 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(part(1_000_000_f64)),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1), 2), false);
+            assert_eq!(timings.is_day_complete(day!(1), 1), true);
         }
 
         #[test]
@@ -307,20 +933,71 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1), 2), false);
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{test_helpers::part, PartStatus, Status, Timing, Timings},
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(2),
+                        status: Status::Solved,
+                        part_statuses: [PartStatus::Solved; 2],
+                        part_1: Some(part(45_000_000_f64)),
+                        part_2: Some(part(20_000_000_f64)),
+                        total_nanos: 6.5e+7,
+                    },
+                    Timing {
+                        day: day!(4),
+                        status: Status::Panicked,
+                        part_statuses: [PartStatus::Solved; 2],
+                        part_1: None,
+                        part_2: None,
+                        total_nanos: 0_f64,
+                    },
+                ],
+            };
+
+            let changes = timings.compare(&other);
+            assert_eq!(changes.len(), 3);
+
+            assert_eq!(changes[0].day, day!(2));
+            assert_eq!(changes[0].part, 1);
+            assert_eq!(changes[0].percent(), Some(50_f64));
+            assert_eq!(changes[0].is_regression(10_f64), true);
+
+            assert_eq!(changes[1].percent(), Some(-50_f64));
+            assert_eq!(changes[1].is_regression(10_f64), false);
+
+            assert_eq!(changes[2].day, day!(4));
+            assert_eq!(changes[2].percent(), None);
+            assert_eq!(changes[2].is_regression(10_f64), true);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartStatus, Status, Timing, Timings},
         };
 
         use super::get_mock_timings;
@@ -331,6 +1008,8 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -351,6 +1030,8 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -0,0 +1,531 @@
+/// Module that exports stored timings for use outside of the readme, e.g. in spreadsheets or dashboards.
+use std::{fmt::Write, fs, io::Error, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::timings::{format_nanos, PartTiming, Timing, Timings};
+
+/// Version of the exported JSON document. Bump when making breaking changes to its schema.
+const JSON_SCHEMA_VERSION: f64 = 1_f64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Svg,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            "svg" => Ok(ExportFormat::Svg),
+            _ => Err(format!(
+                "unknown export format `{s}`, expected one of `csv`, `json`, `svg`."
+            )),
+        }
+    }
+}
+
+/// Writes the timings to `path` in the given format.
+pub fn export(timings: &Timings, format: ExportFormat, path: &Path) -> Result<(), Error> {
+    let mut timings = timings.clone();
+    timings.data.sort_unstable_by_key(|t| t.day);
+
+    let contents = match format {
+        ExportFormat::Csv => to_csv(&timings),
+        ExportFormat::Json => to_json(&timings),
+        ExportFormat::Svg => to_svg(&timings),
+    };
+
+    fs::write(path, contents)
+}
+
+/* -------------------------------------------------------------------------- */
+
+const PART_FIELDS: [&str; 8] = [
+    "nanos",
+    "samples",
+    "min_nanos",
+    "median_nanos",
+    "parse_nanos",
+    "total_bytes",
+    "peak_bytes",
+    "allocations",
+];
+
+fn to_csv(timings: &Timings) -> String {
+    let mut header = vec!["day".to_string(), "status".to_string()];
+    for part in [1, 2] {
+        header.extend(
+            PART_FIELDS
+                .iter()
+                .map(|field| format!("part_{part}_{field}")),
+        );
+    }
+    header.push("total_nanos".into());
+
+    let mut lines = vec![header.join(",")];
+
+    for timing in &timings.data {
+        let mut row = vec![
+            timing.day.into_inner().to_string(),
+            timing.status.as_str().to_string(),
+        ];
+
+        for part in [1, 2] {
+            let part = timing.part(part);
+            row.push(format_number(part.map(|p| p.nanos)));
+            row.push(
+                part.and_then(|p| p.samples)
+                    .map_or_else(String::new, |s| s.to_string()),
+            );
+            row.push(format_number(part.and_then(|p| p.min_nanos)));
+            row.push(format_number(part.and_then(|p| p.median_nanos)));
+            row.push(format_number(part.and_then(|p| p.parse_nanos)));
+
+            let memory = part.and_then(|p| p.memory);
+            for value in [
+                memory.map(|m| m.total_bytes),
+                memory.map(|m| m.peak_bytes),
+                memory.map(|m| m.allocations),
+            ] {
+                row.push(value.map_or_else(String::new, |v| v.to_string()));
+            }
+        }
+
+        row.push(format_number(Some(timing.total_nanos)));
+        lines.push(row.join(","));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn format_number(value: Option<f64>) -> String {
+    value.map_or_else(String::new, |v| v.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A JSON value that keeps its object keys in order, so repeated exports only differ where timings do.
+enum OrderedJson {
+    Value(JsonValue),
+    Array(Vec<OrderedJson>),
+    Object(Vec<(&'static str, OrderedJson)>),
+}
+
+impl OrderedJson {
+    /// Writes the value, indenting every level of nesting by two spaces.
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = |depth: usize| "  ".repeat(depth);
+
+        let (open, close, items): (char, char, Vec<(Option<&str>, &OrderedJson)>) = match self {
+            OrderedJson::Value(value) => {
+                // stringifying numbers, strings and nulls cannot fail.
+                out.push_str(&value.stringify().unwrap());
+                return;
+            }
+            OrderedJson::Array(values) => ('[', ']', values.iter().map(|v| (None, v)).collect()),
+            OrderedJson::Object(entries) => (
+                '{',
+                '}',
+                entries.iter().map(|(k, v)| (Some(*k), v)).collect(),
+            ),
+        };
+
+        out.push(open);
+
+        for (i, (key, value)) in items.iter().enumerate() {
+            out.push_str(if i == 0 { "\n" } else { ",\n" });
+            out.push_str(&indent(depth + 1));
+            if let Some(key) = key {
+                let _ = write!(out, "\"{key}\": ");
+            }
+            value.write(out, depth + 1);
+        }
+
+        if !items.is_empty() {
+            out.push('\n');
+            out.push_str(&indent(depth));
+        }
+
+        out.push(close);
+    }
+}
+
+fn to_json(timings: &Timings) -> String {
+    let json = OrderedJson::Object(vec![
+        (
+            "schema_version",
+            OrderedJson::Value(JsonValue::Number(JSON_SCHEMA_VERSION)),
+        ),
+        (
+            "total_nanos",
+            // NOTE: summing no floats yields `-0`, so the sum starts at `0` explicitly.
+            OrderedJson::Value(JsonValue::Number(
+                timings
+                    .data
+                    .iter()
+                    .fold(0_f64, |sum, t| sum + t.total_nanos),
+            )),
+        ),
+        (
+            "days",
+            OrderedJson::Array(timings.data.iter().map(day_to_json).collect()),
+        ),
+    ]);
+
+    let mut out = String::new();
+    json.write(&mut out, 0);
+    out.push('\n');
+    out
+}
+
+fn day_to_json(timing: &Timing) -> OrderedJson {
+    OrderedJson::Object(vec![
+        (
+            "day",
+            OrderedJson::Value(JsonValue::Number(f64::from(timing.day.into_inner()))),
+        ),
+        (
+            "status",
+            OrderedJson::Value(JsonValue::String(timing.status.as_str().into())),
+        ),
+        (
+            "total_nanos",
+            OrderedJson::Value(JsonValue::Number(timing.total_nanos)),
+        ),
+        (
+            "parts",
+            OrderedJson::Array(
+                [1, 2]
+                    .into_iter()
+                    .filter_map(|part| timing.part(part).map(|t| part_to_json(part, &t)))
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn part_to_json(part: u8, timing: &PartTiming) -> OrderedJson {
+    let number = |v: f64| OrderedJson::Value(JsonValue::Number(v));
+    let number_or_null = |v: Option<f64>| v.map_or(OrderedJson::Value(JsonValue::Null), number);
+
+    let memory = timing
+        .memory
+        .as_ref()
+        .map_or(OrderedJson::Value(JsonValue::Null), |memory| {
+            OrderedJson::Object(vec![
+                ("total_bytes", number(memory.total_bytes as f64)),
+                ("peak_bytes", number(memory.peak_bytes as f64)),
+                ("allocations", number(memory.allocations as f64)),
+            ])
+        });
+
+    OrderedJson::Object(vec![
+        ("part", number(f64::from(part))),
+        ("nanos", number(timing.nanos)),
+        ("samples", number_or_null(timing.samples.map(|s| s as f64))),
+        ("min_nanos", number_or_null(timing.min_nanos)),
+        ("median_nanos", number_or_null(timing.median_nanos)),
+        ("parse_nanos", number_or_null(timing.parse_nanos)),
+        ("memory", memory),
+    ])
+}
+
+/* -------------------------------------------------------------------------- */
+
+const CHART_HEIGHT: f64 = 240_f64;
+const MARGIN_TOP: f64 = 40_f64;
+const MARGIN_LEFT: f64 = 70_f64;
+const MARGIN_RIGHT: f64 = 20_f64;
+const MARGIN_BOTTOM: f64 = 50_f64;
+const BAR_WIDTH: f64 = 12_f64;
+const GROUP_WIDTH: f64 = 36_f64;
+const PART_COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+
+/// Renders a grouped bar chart with one group per day and a logarithmic y axis.
+fn to_svg(timings: &Timings) -> String {
+    let values: Vec<f64> = timings
+        .data
+        .iter()
+        .flat_map(|t| [t.part_nanos(1), t.part_nanos(2)])
+        .flatten()
+        .filter(|nanos| *nanos > 0_f64)
+        .collect();
+
+    // round the axis to whole decades, spanning at least one.
+    let min_exp = values
+        .iter()
+        .map(|v| v.log10().floor())
+        .fold(f64::INFINITY, f64::min);
+    let max_exp = values
+        .iter()
+        .map(|v| v.log10().ceil())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_exp, max_exp) = if values.is_empty() {
+        (0_f64, 1_f64)
+    } else {
+        (min_exp, max_exp.max(min_exp + 1_f64))
+    };
+
+    let y = |nanos: f64| {
+        let ratio = (nanos.log10() - min_exp) / (max_exp - min_exp);
+        MARGIN_TOP + CHART_HEIGHT * (1_f64 - ratio.clamp(0_f64, 1_f64))
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let chart_width = GROUP_WIDTH * timings.data.len().max(1) as f64;
+    let width = MARGIN_LEFT + chart_width + MARGIN_RIGHT;
+    let height = MARGIN_TOP + CHART_HEIGHT + MARGIN_BOTTOM;
+    let baseline = MARGIN_TOP + CHART_HEIGHT;
+
+    let mut svg = String::new();
+
+    // writing to a `String` cannot fail.
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="{width}" height="{height}" fill="#ffffff"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{MARGIN_LEFT}" y="20" font-size="14" font-weight="bold">Benchmarks</text>"#
+    );
+
+    for (i, color) in PART_COLORS.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = width - MARGIN_RIGHT - 130_f64 + 65_f64 * i as f64;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x}" y="10" width="10" height="10" fill="{color}"/><text x="{}" y="19">Part {}</text>"#,
+            x + 14_f64,
+            i + 1
+        );
+    }
+
+    let mut exp = min_exp;
+    while exp <= max_exp {
+        let nanos = 10_f64.powf(exp);
+        let line_y = y(nanos);
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{MARGIN_LEFT}" y1="{line_y}" x2="{}" y2="{line_y}" stroke="#dddddd"/><text x="{}" y="{}" text-anchor="end">{}</text>"##,
+            MARGIN_LEFT + chart_width,
+            MARGIN_LEFT - 6_f64,
+            line_y + 4_f64,
+            format_nanos(nanos)
+        );
+        exp += 1_f64;
+    }
+
+    for (i, timing) in timings.data.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let group_x = MARGIN_LEFT + GROUP_WIDTH * i as f64;
+
+        for (p, color) in PART_COLORS.iter().enumerate() {
+            let Some(nanos) = timing.part_nanos(1 + u8::try_from(p).unwrap()) else {
+                continue;
+            };
+
+            #[allow(clippy::cast_precision_loss)]
+            let x = group_x + (GROUP_WIDTH - 2_f64 * BAR_WIDTH) / 2_f64 + BAR_WIDTH * p as f64;
+            let top = if nanos > 0_f64 { y(nanos) } else { baseline };
+
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x}" y="{top}" width="{BAR_WIDTH}" height="{}" fill="{color}"><title>Day {} Part {}: {}</title></rect>"#,
+                baseline - top,
+                timing.day,
+                p + 1,
+                format_nanos(nanos)
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+            group_x + GROUP_WIDTH / 2_f64,
+            baseline + 16_f64,
+            timing.day
+        );
+    }
+
+    let _ = writeln!(
+        svg,
+        r##"<line x1="{MARGIN_LEFT}" y1="{baseline}" x2="{}" y2="{baseline}" stroke="#333333"/>"##,
+        MARGIN_LEFT + chart_width
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{}" text-anchor="middle">Day</text>"#,
+        MARGIN_LEFT + chart_width / 2_f64,
+        baseline + 36_f64
+    );
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use crate::{
+        day,
+        template::timings::{
+            test_helpers::part, MemoryUsage, PartStatus, PartTiming, Status, Timing, Timings,
+        },
+    };
+
+    use super::{to_csv, to_json, to_svg, ExportFormat};
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(PartTiming {
+                        min_nanos: Some(1000_f64),
+                        median_nanos: Some(1400_f64),
+                        parse_nanos: Some(500_f64),
+                        memory: Some(MemoryUsage {
+                            total_bytes: 276,
+                            peak_bytes: 232,
+                            allocations: 3,
+                        }),
+                        ..part(1500_f64)
+                    }),
+                    part_2: None,
+                    total_nanos: 1500_f64,
+                },
+                Timing {
+                    day: day!(2),
+                    status: Status::Solved,
+                    part_statuses: [PartStatus::Solved; 2],
+                    part_1: Some(PartTiming {
+                        samples: None,
+                        ..part(2e6)
+                    }),
+                    part_2: Some(part(3e8)),
+                    total_nanos: 302e6,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_export_formats() {
+        assert_eq!(ExportFormat::from_str("csv"), Ok(ExportFormat::Csv));
+        assert_eq!(ExportFormat::from_str("svg"), Ok(ExportFormat::Svg));
+        assert!(ExportFormat::from_str("png").is_err());
+    }
+
+    #[test]
+    fn exports_csv() {
+        let expected = [
+            "day,status,part_1_nanos,part_1_samples,part_1_min_nanos,part_1_median_nanos,part_1_parse_nanos,part_1_total_bytes,part_1_peak_bytes,part_1_allocations,part_2_nanos,part_2_samples,part_2_min_nanos,part_2_median_nanos,part_2_parse_nanos,part_2_total_bytes,part_2_peak_bytes,part_2_allocations,total_nanos",
+            "1,solved,1500,10,1000,1400,500,276,232,3,,,,,,,,,1500",
+            "2,solved,2000000,,,,,,,,300000000,10,,,,,,,302000000",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(to_csv(&get_mock_timings()), expected);
+    }
+
+    #[test]
+    fn exports_json() {
+        let expected = r#"{
+  "schema_version": 1,
+  "total_nanos": 302001500,
+  "days": [
+    {
+      "day": 1,
+      "status": "solved",
+      "total_nanos": 1500,
+      "parts": [
+        {
+          "part": 1,
+          "nanos": 1500,
+          "samples": 10,
+          "min_nanos": 1000,
+          "median_nanos": 1400,
+          "parse_nanos": 500,
+          "memory": {
+            "total_bytes": 276,
+            "peak_bytes": 232,
+            "allocations": 3
+          }
+        }
+      ]
+    },
+    {
+      "day": 2,
+      "status": "solved",
+      "total_nanos": 302000000,
+      "parts": [
+        {
+          "part": 1,
+          "nanos": 2000000,
+          "samples": null,
+          "min_nanos": null,
+          "median_nanos": null,
+          "parse_nanos": null,
+          "memory": null
+        },
+        {
+          "part": 2,
+          "nanos": 300000000,
+          "samples": 10,
+          "min_nanos": null,
+          "median_nanos": null,
+          "parse_nanos": null,
+          "memory": null
+        }
+      ]
+    }
+  ]
+}
+"#;
+        let json = to_json(&get_mock_timings());
+        assert_eq!(json, expected);
+        assert!(JsonValue::from_str(&json).is_ok());
+    }
+
+    #[test]
+    fn exports_empty_json() {
+        let expected = "{\n  \"schema_version\": 1,\n  \"total_nanos\": 0,\n  \"days\": []\n}\n";
+        assert_eq!(to_json(&Timings::default()), expected);
+    }
+
+    #[test]
+    fn exports_svg() {
+        let svg = to_svg(&get_mock_timings());
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<title>").count(), 3);
+        assert!(svg.contains("<title>Day 02 Part 2: 300.0ms</title>"));
+        // axis spans 1µs to 1s in whole decades.
+        assert!(svg.contains(">1.0µs</text>"));
+        assert!(svg.contains(">1.0s</text>"));
+    }
+
+    #[test]
+    fn exports_empty_svg() {
+        let svg = to_svg(&Timings::default());
+        assert_eq!(svg.matches("<title>").count(), 0);
+    }
+}